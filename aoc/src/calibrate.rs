@@ -0,0 +1,196 @@
+use crate::days;
+use anyhow::Context;
+use clap::ValueEnum;
+use common::cli::Part;
+use day01::{Kind, LineReport, Vocabulary};
+use std::{fmt::Write, fs, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum Format {
+    Text,
+    Csv,
+    Json,
+}
+
+const HEADER: &str = "line,text,tokens,first,last,calibration,no_digits";
+
+fn kind(kind: Kind) -> &'static str {
+    match kind {
+        Kind::Digit => "digit",
+        Kind::Word => "word",
+    }
+}
+
+fn text(lines: &[LineReport]) -> String {
+    let mut out = String::new();
+
+    for line in lines {
+        writeln!(out, "Line {}: {}", line.number, line.text).unwrap();
+
+        for token in &line.tokens {
+            let span = format!("{}..{}", token.span.start, token.span.end);
+            let kind = kind(token.kind);
+            writeln!(
+                out,
+                "  {span:<10}{kind:<7}{:<10}{}",
+                token.text, token.value
+            )
+            .unwrap();
+        }
+
+        match (line.first, line.last, line.calibration) {
+            (Some(first), Some(last), Some(calibration)) => writeln!(
+                out,
+                "  first {first}, last {last}, calibration {calibration}"
+            ),
+            _ => writeln!(out, "  no digits, adds nothing"),
+        }
+        .unwrap();
+    }
+
+    let sum = lines
+        .iter()
+        .filter_map(|line| line.calibration)
+        .sum::<u32>();
+    let flagged = lines.iter().filter(|line| line.no_digits).count();
+    writeln!(
+        out,
+        "\nSum {sum} over {} lines, {flagged} without digits",
+        lines.len()
+    )
+    .unwrap();
+
+    out
+}
+
+/// A CSV field, quoted if it needs to be.
+fn field(value: &str) -> String {
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+/// One row per line, with its tokens in one field as
+/// `kind:text=value@start..end`, like `word:two=2@0..3`, separated by spaces.
+fn csv(lines: &[LineReport]) -> String {
+    let mut out = format!("{HEADER}\n");
+    let optional = |value: Option<u32>| value.map(|v| v.to_string()).unwrap_or_default();
+
+    for line in lines {
+        let tokens = line
+            .tokens
+            .iter()
+            .map(|token| {
+                format!(
+                    "{}:{}={}@{}..{}",
+                    kind(token.kind),
+                    token.text,
+                    token.value,
+                    token.span.start,
+                    token.span.end
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            line.number,
+            field(&line.text),
+            field(&tokens),
+            optional(line.first),
+            optional(line.last),
+            optional(line.calibration),
+            line.no_digits
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+pub fn format(lines: &[LineReport], format: Format) -> anyhow::Result<String> {
+    Ok(match format {
+        Format::Text => text(lines),
+        Format::Csv => csv(lines),
+        Format::Json => serde_json::to_string_pretty(lines)? + "\n",
+    })
+}
+
+/// Reports how every line of day 1's puzzle input, or one of its examples,
+/// was read.
+pub fn run(
+    example: Option<usize>,
+    vocabulary: &Vocabulary,
+    format: Format,
+    output: Option<&Path>,
+) -> anyhow::Result<()> {
+    let day = days::day(1);
+
+    let input = match example {
+        Some(number) => (day.example)(number, Part::Both)?.0.input.to_string(),
+        None => day.read_input()?,
+    };
+
+    let report = self::format(&day01::report(&input, vocabulary), format)?;
+
+    match output {
+        Some(path) => {
+            fs::write(path, report).with_context(|| format!("could not write {}", path.display()))
+        }
+        None => {
+            print!("{report}");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines() -> Vec<LineReport> {
+        day01::report("two1nine\nx,\"y\"", &Vocabulary::english())
+    }
+
+    #[test]
+    fn formats_text() {
+        let res = format(&lines(), Format::Text).unwrap();
+
+        assert_eq!(
+            res,
+            "Line 1: two1nine\n  \
+             0..3      word   two       2\n  \
+             3..4      digit  1         1\n  \
+             4..8      word   nine      9\n  \
+             first 2, last 9, calibration 29\n\
+             Line 2: x,\"y\"\n  \
+             no digits, adds nothing\n\
+             \n\
+             Sum 29 over 2 lines, 1 without digits\n"
+        );
+    }
+
+    #[test]
+    fn formats_csv_and_json() {
+        let res = format(&lines(), Format::Csv).unwrap();
+        assert_eq!(
+            res,
+            format!(
+                "{HEADER}\n\
+                 1,two1nine,word:two=2@0..3 digit:1=1@3..4 word:nine=9@4..8,2,9,29,false\n\
+                 2,\"x,\"\"y\"\"\",,,,,true\n"
+            )
+        );
+
+        let res = format(&lines(), Format::Json).unwrap();
+        let json = serde_json::from_str::<serde_json::Value>(&res).unwrap();
+        assert_eq!(json[0]["tokens"][2]["span"]["start"], 4);
+        assert_eq!(json[0]["tokens"][2]["kind"], "word");
+        assert_eq!(json[0]["calibration"], 29);
+        assert_eq!(json[1]["calibration"], serde_json::Value::Null);
+        assert_eq!(json[1]["no_digits"], true);
+    }
+}
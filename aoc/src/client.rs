@@ -0,0 +1,270 @@
+use crate::config::{Config, SESSION_VAR};
+use anyhow::{anyhow, bail, Context};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, time::Duration};
+
+/// The year of the puzzles this workspace solves.
+pub const YEAR: u16 = 2023;
+
+/// The site's verdict on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way.
+    Wrong,
+    /// Not judged, as an answer was given too recently.
+    RateLimited,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::RateLimited => write!(f, "rate limited"),
+        }
+    }
+}
+
+/// The page the site answers a submission with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// Time left before another answer is accepted, when rate limited.
+    pub wait: Option<Duration>,
+}
+
+impl Response {
+    pub fn parse(page: &str) -> anyhow::Result<Self> {
+        let outcome = if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if page.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if page.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::RateLimited
+        } else if page.contains("You don't seem to be solving the right level") {
+            bail!("that part is already solved or not unlocked yet")
+        } else {
+            bail!("could not understand the response")
+        };
+
+        Ok(Self {
+            outcome,
+            wait: wait(page),
+        })
+    }
+}
+
+/// The wait in a page like "You have 1m 5s left to wait."
+fn wait(page: &str) -> Option<Duration> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let start = before.rfind("You have ")? + "You have ".len();
+    humantime::parse_duration(&before[start..]).ok()
+}
+
+/// A client for the Advent of Code site, or anything that answers like it.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&user_agent(config.contact.as_deref()))
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: config.base_url().trim_end_matches('/').to_string(),
+            session: config.session(),
+        }
+    }
+
+    /// Downloads the puzzle input of a day.
+    pub fn input(&self, day: u8) -> anyhow::Result<String> {
+        let path = format!("/{YEAR}/day/{day}/input");
+
+        let body = self
+            .request("GET", &path)?
+            .call()
+            .map_err(|e| self.error(e, day))?
+            .into_string()
+            .context("could not read the response")?;
+
+        non_empty(body)
+    }
+
+    /// Submits an answer to one part of a day's puzzle.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> anyhow::Result<Response> {
+        let path = format!("/{YEAR}/day/{day}/answer");
+
+        let page = self
+            .request("POST", &path)?
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| self.error(e, day))?
+            .into_string()
+            .context("could not read the response")?;
+
+        Response::parse(&non_empty(page)?)
+    }
+
+    fn request(&self, method: &str, path: &str) -> anyhow::Result<ureq::Request> {
+        let session = self.session.as_ref().with_context(|| {
+            format!("no session cookie, set {SESSION_VAR} or `session` in the config")
+        })?;
+
+        Ok(self
+            .agent
+            .request(method, &format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={session}")))
+    }
+
+    fn error(&self, error: ureq::Error, day: u8) -> anyhow::Error {
+        match error {
+            ureq::Error::Status(404, _) => anyhow!("day {day} is not unlocked yet"),
+            ureq::Error::Status(400 | 401 | 403 | 500, _) => {
+                anyhow!("the session cookie was rejected, it may have expired")
+            }
+            ureq::Error::Status(status, _) => anyhow!("the server answered with status {status}"),
+            ureq::Error::Transport(e) => {
+                anyhow!(e).context(format!("could not reach {}", self.base_url))
+            }
+        }
+    }
+}
+
+/// Identifies the tool, and whoever runs it if they said how to reach them,
+/// as the site asks of automated clients.
+fn user_agent(contact: Option<&str>) -> String {
+    let tool = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+    match contact {
+        Some(contact) => format!("{tool} ({contact})"),
+        None => tool.to_string(),
+    }
+}
+
+/// Fails on an empty body, which the site sends for requests it did not
+/// expect.
+fn non_empty(body: String) -> anyhow::Result<String> {
+    if body.trim().is_empty() {
+        bail!("the server sent an empty response");
+    }
+
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::Server;
+
+    fn client(server: &Server) -> Client {
+        Client::new(&Config {
+            session: Some(String::from("abc")),
+            base_url: Some(server.url.clone()),
+            contact: Some(String::from("me@example.com")),
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn downloads_input() {
+        let server = Server::start(vec![(200, "1abc2\n")]);
+        assert_eq!(client(&server).input(1).unwrap(), "1abc2\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2023/day/1/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc"));
+        assert_eq!(
+            requests[0].header("user-agent"),
+            Some(concat!(
+                "aoc-runner/",
+                env!("CARGO_PKG_VERSION"),
+                " (me@example.com)"
+            ))
+        );
+    }
+
+    #[test]
+    fn reports_errors() {
+        let server = Server::start(vec![(404, "Not found"), (400, "Please log in")]);
+        let client = client(&server);
+
+        let res = client.input(25).unwrap_err();
+        assert_eq!(res.to_string(), "day 25 is not unlocked yet");
+
+        let res = client.input(1).unwrap_err();
+        assert!(res.to_string().contains("session cookie was rejected"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let page = "<article><p>That's not the right answer; your answer is too low. \
+                    Please wait one minute before trying again.</p></article>";
+        let server = Server::start(vec![(200, page)]);
+
+        let res = client(&server).submit(4, 2, "13").unwrap();
+        assert_eq!(res.outcome, Outcome::TooLow);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2023/day/4/answer");
+        assert_eq!(requests[0].body, "level=2&answer=13");
+    }
+
+    #[test]
+    fn parses_responses() {
+        let outcome = |page| Response::parse(page).unwrap().outcome;
+
+        assert_eq!(
+            outcome("That's the right answer! You are one gold star closer."),
+            Outcome::Correct
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too high."),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            outcome("That's not the right answer. If you're stuck, make sure..."),
+            Outcome::Wrong
+        );
+
+        let res = Response::parse("You gave an answer too recently. You have 1m 5s left to wait.")
+            .unwrap();
+        assert_eq!(res.outcome, Outcome::RateLimited);
+        assert_eq!(res.wait, Some(Duration::from_secs(65)));
+
+        let res =
+            Response::parse("You gave an answer too recently. You have 38s left to wait.").unwrap();
+        assert_eq!(res.wait, Some(Duration::from_secs(38)));
+
+        assert!(Response::parse("You don't seem to be solving the right level.").is_err());
+        assert!(Response::parse("<html></html>").is_err());
+    }
+
+    #[test]
+    fn needs_a_session() {
+        let client = Client {
+            session: None,
+            ..Client::new(&Config::default())
+        };
+
+        let res = client.input(1).unwrap_err();
+        assert!(res.to_string().starts_with("no session cookie"));
+    }
+}
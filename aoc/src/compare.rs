@@ -0,0 +1,48 @@
+use anyhow::bail;
+use common::history::{self, Status};
+use humantime::format_duration;
+use std::path::Path;
+
+pub fn run(path: &Path, threshold: f64) -> anyhow::Result<()> {
+    let records = history::load(path)?;
+    let comparisons = history::compare(&records, threshold);
+
+    println!(
+        "{:<5}{:<8}{:<18}{:<16}{:>18}{:>18}{:>10}  Status",
+        "Day", "Stage", "Input", "Revision", "Baseline", "Latest", "Change"
+    );
+
+    for comparison in &comparisons {
+        let baseline = comparison
+            .baseline
+            .map(|b| format_duration(b).to_string())
+            .unwrap_or_else(|| String::from("-"));
+        let change = comparison
+            .change()
+            .map(|c| format!("{c:+.1}%"))
+            .unwrap_or_else(|| String::from("-"));
+
+        println!(
+            "{:<5}{:<8}{:<18}{:<16}{:>18}{:>18}{:>10}  {}",
+            comparison.day,
+            comparison.stage.to_string(),
+            comparison.input_hash,
+            comparison.revision,
+            baseline,
+            format_duration(comparison.latest).to_string(),
+            change,
+            comparison.status,
+        );
+    }
+
+    let regressions = comparisons
+        .iter()
+        .filter(|c| c.status == Status::Regression)
+        .count();
+
+    if regressions > 0 {
+        bail!("{regressions} stage(s) slower than their baseline by more than {threshold}%");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use anyhow::Context;
+use serde::Deserialize;
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+/// Personal settings for talking to the Advent of Code site, at the root of
+/// the workspace. It holds the session cookie, so it is not committed.
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc.toml");
+
+/// Environment variable that overrides the session cookie in the config.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    /// Site to talk to instead of adventofcode.com.
+    pub base_url: Option<String>,
+    /// Directory that downloaded inputs are cached in, and read from for days
+    /// without an input of their own.
+    pub cache_dir: Option<PathBuf>,
+    /// How the site can reach whoever runs the tool, sent in the User-Agent.
+    pub contact: Option<String>,
+}
+
+impl Config {
+    /// Loads the config file, treating a missing file as empty, with the
+    /// session cookie from the environment if it is set there.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let config = match common::read_optional(path)? {
+            Some(data) => toml::from_str(&data)
+                .with_context(|| format!("could not parse {}", path.display()))?,
+            None => Self::default(),
+        };
+
+        Ok(config.with_session_var(env::var(SESSION_VAR).ok()))
+    }
+
+    fn with_session_var(self, var: Option<String>) -> Self {
+        match var.filter(|session| !session.trim().is_empty()) {
+            Some(session) => Self {
+                session: Some(session),
+                ..self
+            },
+            None => self,
+        }
+    }
+
+    pub fn session(&self) -> Option<String> {
+        self.session
+            .as_ref()
+            .map(|session| session.trim().to_string())
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL)
+    }
+
+    pub fn cache_dir(&self) -> PathBuf {
+        self.cache_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(common::cli::CACHE_DIR))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults() {
+        // The session may come from the environment, which the test leaves
+        // alone.
+        let config = Config::load(Path::new("/nonexistent/aoc.toml")).unwrap();
+        let config = Config {
+            session: None,
+            ..config
+        };
+        assert_eq!(config, Config::default());
+        assert_eq!(config.base_url(), "https://adventofcode.com");
+        assert!(config.cache_dir().ends_with("inputs"));
+    }
+
+    #[test]
+    fn parses() {
+        let config: Config = toml::from_str(
+            r#"
+            session = "abc"
+            base_url = "http://localhost:8080"
+            cache_dir = "/tmp/inputs"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url(), "http://localhost:8080");
+        assert_eq!(config.cache_dir(), PathBuf::from("/tmp/inputs"));
+
+        assert!(toml::from_str::<Config>("sesion = \"abc\"").is_err());
+    }
+
+    #[test]
+    fn session_var() {
+        let config = Config {
+            session: Some(String::from("abc")),
+            ..Config::default()
+        };
+
+        let res = config
+            .clone()
+            .with_session_var(Some(String::from(" def\n")));
+        assert_eq!(res.session().as_deref(), Some("def"));
+
+        let res = config.clone().with_session_var(Some(String::from(" ")));
+        assert_eq!(res.session().as_deref(), Some("abc"));
+
+        let res = config.with_session_var(None);
+        assert_eq!(res.session().as_deref(), Some("abc"));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::config::{self, Config};
+use common::{
+    answers::Answers,
+    bench::{self, Timed},
+    cli::{BenchArgs, Input, Part},
+    example::{self, Example},
+    generate,
+    parse::Mode,
+    Solution,
+};
+use std::path::{Path, PathBuf};
+
+/// Type-erased entry points for one day's [`Solution`].
+pub struct Day {
+    pub day: u8,
+    pub input: &'static str,
+    pub examples: &'static [Example],
+    pub solve: fn(&str, Part) -> anyhow::Result<Answers>,
+    pub run: fn(&str, Part, Mode, &BenchArgs) -> anyhow::Result<()>,
+    pub example: fn(usize, Part) -> anyhow::Result<(&'static Example, Part)>,
+    pub generate: fn(usize, u64) -> String,
+    pub timed: fn(&str, Part, Mode) -> Vec<Timed>,
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            input: S::INPUT,
+            examples: S::EXAMPLES,
+            solve: common::solve::<S>,
+            run: bench::run::<S>,
+            example: example::select::<S>,
+            generate: generate::input::<S>,
+            timed: bench::timed::<S>,
+        }
+    }
+
+    /// Reads the puzzle input, or the one `aoc fetch` downloaded if the day
+    /// has none of its own.
+    pub fn read_input(&self) -> anyhow::Result<String> {
+        Input::Default.read_cached(self.input, &cache_dir()?)
+    }
+}
+
+/// Where `aoc fetch` downloads inputs to, as the config has it.
+pub fn cache_dir() -> anyhow::Result<PathBuf> {
+    Ok(Config::load(Path::new(config::DEFAULT_PATH))?.cache_dir())
+}
+
+pub const DAYS: [Day; 11] = [
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+];
+
+pub fn day(day: u8) -> &'static Day {
+    &DAYS[day as usize - 1]
+}
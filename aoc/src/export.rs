@@ -0,0 +1,99 @@
+use crate::days;
+use anyhow::{bail, Context};
+use clap::ValueEnum;
+use common::{cli::Part, Solution};
+use grid::{
+    image::{self, Rgb},
+    Grid,
+};
+use std::{fs, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum Format {
+    Ppm,
+    Svg,
+}
+
+impl Format {
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Ppm => "ppm",
+            Self::Svg => "svg",
+        }
+    }
+
+    fn encode(&self, image: &Grid<Rgb>, scale: usize) -> Vec<u8> {
+        match self {
+            Self::Ppm => image::ppm(image, scale),
+            Self::Svg => image::svg(image, scale).into_bytes(),
+        }
+    }
+}
+
+/// The images of a day's puzzle input, each with the name it is saved
+/// under.
+fn images(day: u8, input: &str) -> anyhow::Result<Vec<(String, Grid<Rgb>)>> {
+    let name = format!("day{day:02}");
+
+    let images = match day {
+        3 => vec![(name, day03::Day03::parse(input)?.render())],
+        10 => vec![(name, day10::render(&day10::Day10::parse(input)?)?)],
+        11 => {
+            let image = day11::Day11::parse(input)?;
+            vec![
+                (format!("{name}-before"), day11::render(&image, 1)),
+                (format!("{name}-after"), day11::render(&image, 2)),
+            ]
+        }
+        _ => bail!("day {day} has no images, only days 3, 10 and 11 do"),
+    };
+
+    Ok(images)
+}
+
+/// Renders a day's puzzle input, or one of its examples, into `dir`.
+pub fn run(
+    day: u8,
+    example: Option<usize>,
+    format: Format,
+    scale: usize,
+    dir: &Path,
+) -> anyhow::Result<()> {
+    let day = days::day(day);
+
+    let input = match example {
+        Some(number) => (day.example)(number, Part::Both)?.0.input.to_string(),
+        None => day.read_input()?,
+    };
+
+    fs::create_dir_all(dir).with_context(|| format!("could not create {}", dir.display()))?;
+
+    for (name, image) in images(day.day, &input)? {
+        let path = dir.join(format!("{name}.{}", format.extension()));
+        fs::write(&path, format.encode(&image, scale))
+            .with_context(|| format!("could not write {}", path.display()))?;
+
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_grid_days() {
+        for (day, count) in [(3, 1), (10, 1), (11, 2)] {
+            let example = days::day(day).examples[0].input;
+            assert_eq!(images(day, example).unwrap().len(), count);
+        }
+
+        let res = images(7, "").unwrap_err();
+        assert_eq!(
+            res.to_string(),
+            "day 7 has no images, only days 3, 10 and 11 do"
+        );
+    }
+}
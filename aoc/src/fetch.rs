@@ -0,0 +1,116 @@
+use crate::{client::Client, config::Config};
+use anyhow::Context;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    /// The input was already in the cache, and nothing was downloaded.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Path of a day's input in the cache.
+pub fn cache_path(cache_dir: &Path, day: u8) -> PathBuf {
+    cache_dir.join(format!("day{day:02}.txt"))
+}
+
+/// Makes sure a day's input is in the cache, downloading it only if it is
+/// not there yet.
+pub fn fetch(client: &Client, cache_dir: &Path, day: u8) -> anyhow::Result<Fetched> {
+    let path = cache_path(cache_dir, day);
+
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.input(day)?;
+
+    fs::create_dir_all(cache_dir)
+        .with_context(|| format!("could not create {}", cache_dir.display()))?;
+    fs::write(&path, input).with_context(|| format!("could not write {}", path.display()))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+pub fn run(day: u8, config: &Path, cache_dir: Option<PathBuf>) -> anyhow::Result<()> {
+    let config = Config::load(config)?;
+    let cache_dir = cache_dir.unwrap_or_else(|| config.cache_dir());
+
+    match fetch(&Client::new(&config), &cache_dir, day)? {
+        Fetched::Cached(path) => println!("Day {day:02} is already cached at {}", path.display()),
+        Fetched::Downloaded(path) => println!("Day {day:02} downloaded to {}", path.display()),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days, mock::Server};
+    use common::cli::{Input, Part};
+    use std::env;
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn client(server: &Server) -> Client {
+        Client::new(&Config {
+            session: Some(String::from("abc")),
+            base_url: Some(server.url.clone()),
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn fetches_each_day_once() {
+        let server = Server::start(vec![(200, "0 3 6 9\n")]);
+        let client = client(&server);
+        let dir = cache_dir("once");
+
+        let path = cache_path(&dir, 9);
+        assert_eq!(
+            fetch(&client, &dir, 9).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "0 3 6 9\n");
+
+        assert_eq!(fetch(&client, &dir, 9).unwrap(), Fetched::Cached(path));
+        assert_eq!(server.requests().len(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn solves_fetched_inputs() {
+        let server = Server::start(vec![(200, "0 3 6 9\n10 13 16 21 30 45\n")]);
+        let dir = cache_dir("solves");
+
+        fetch(&client(&server), &dir, 9).unwrap();
+
+        // A day whose own input is missing reads the fetched one instead.
+        let input = Input::Default
+            .read_cached("/nonexistent/day09/day09.txt", &dir)
+            .unwrap();
+        let answers = (days::day(9).solve)(&input, Part::Both).unwrap();
+        assert_eq!(answers.part1.as_deref(), Some("80"));
+        assert_eq!(answers.part2.as_deref(), Some("2"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn caches_nothing_on_failure() {
+        let server = Server::start(vec![(404, "Not found")]);
+        let dir = cache_dir("failure");
+
+        assert!(fetch(&client(&server), &dir, 25).is_err());
+        assert!(!cache_path(&dir, 25).exists());
+    }
+}
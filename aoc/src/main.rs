@@ -0,0 +1,311 @@
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use common::{
+    answers,
+    cli::{BenchArgs, Part},
+    history,
+    parse::Mode,
+};
+use selection::Selection;
+use std::{fs, path::PathBuf};
+
+mod calibrate;
+mod client;
+mod compare;
+mod config;
+mod days;
+mod export;
+mod fetch;
+#[cfg(test)]
+mod mock;
+mod parallel;
+mod scaffold;
+mod selection;
+mod submit;
+mod verify;
+mod watch;
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2023 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve a day (`7`), a range of days (`3..=5`) or `all`
+    Run {
+        days: Selection,
+
+        /// Which part of each puzzle to solve
+        #[arg(long, value_enum, default_value_t = Part::Both)]
+        part: Part,
+
+        /// Solve the Nth example from each puzzle statement instead
+        #[arg(long, value_name = "N")]
+        example: Option<usize>,
+
+        /// Whether malformed input lines fail the parse or are skipped
+        #[arg(long, value_enum, default_value_t = Mode::Strict)]
+        parse_mode: Mode,
+
+        /// Solve the days concurrently on N threads and print one table
+        #[arg(long, value_name = "N", conflicts_with_all = ["example", "iterations"])]
+        jobs: Option<usize>,
+
+        #[command(flatten)]
+        bench: BenchArgs,
+    },
+    /// Compare the latest benchmark of every stage against its history
+    Compare {
+        /// Allowed slowdown of the median before a stage is flagged, in percent
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// History file written by `run --bench`
+        #[arg(long, value_name = "PATH", default_value = history::DEFAULT_PATH)]
+        history: PathBuf,
+    },
+    /// Solve days and check every answer against the confirmed answers
+    Verify {
+        #[arg(default_value = "all")]
+        days: Selection,
+
+        /// Confirmed answers file
+        #[arg(long, value_name = "PATH", default_value = answers::DEFAULT_PATH)]
+        answers: PathBuf,
+
+        /// Record answers that are not confirmed yet
+        #[arg(long)]
+        accept: bool,
+    },
+    /// Generate a synthetic puzzle input for a day
+    Gen {
+        #[arg(value_parser = selection::day)]
+        day: u8,
+
+        /// How large an input to generate, roughly in lines or grid tiles
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// Seed for the generator; the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Write the input to a file instead of stdout
+        #[arg(long, short, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+    /// Render a grid day's puzzle input as images
+    Export {
+        #[arg(value_parser = selection::day)]
+        day: u8,
+
+        /// Render the Nth example from the puzzle statement instead
+        #[arg(long, value_name = "N")]
+        example: Option<usize>,
+
+        #[arg(long, value_enum, default_value_t = export::Format::Svg)]
+        format: export::Format,
+
+        /// Size of each cell, in pixels
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+
+        /// Directory the images are written to
+        #[arg(long, short, value_name = "DIR", default_value = ".")]
+        output: PathBuf,
+    },
+    /// Report how each line of day 1's calibration document was read
+    Calibrate {
+        /// Read the Nth example from the puzzle statement instead
+        #[arg(long, value_name = "N")]
+        example: Option<usize>,
+
+        /// Words for digits: one of digits, english, french, german, spanish
+        /// and roman, or a vocabulary file
+        #[arg(long, value_name = "NAME|PATH", default_value = "english")]
+        vocabulary: day01::Vocabulary,
+
+        /// Match the words of the vocabulary in any case
+        #[arg(long)]
+        ignore_case: bool,
+
+        /// Read decimal digits of every script, like `３` or `٣`, in the report;
+        /// solving day 1 keeps to the puzzle's 0-9
+        #[arg(long)]
+        unicode_digits: bool,
+
+        #[arg(long, value_enum, default_value_t = calibrate::Format::Text)]
+        format: calibrate::Format,
+
+        /// Write the report to a file instead of stdout
+        #[arg(long, short, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+    /// Download a day's puzzle input into the input cache
+    Fetch {
+        #[arg(value_parser = selection::calendar_day)]
+        day: u8,
+
+        /// Config file with the session cookie and site settings
+        #[arg(long, value_name = "PATH", default_value = config::DEFAULT_PATH)]
+        config: PathBuf,
+
+        /// Cache directory, instead of the one in the config
+        #[arg(long, value_name = "PATH")]
+        cache_dir: Option<PathBuf>,
+    },
+    /// Create the crate for the next day and register it with the runner
+    New {
+        #[arg(value_parser = selection::calendar_day)]
+        day: u8,
+    },
+    /// Solve a day again every time its input or example files change
+    Watch {
+        #[arg(value_parser = selection::day)]
+        day: u8,
+
+        /// Whether malformed input lines fail the parse or are skipped
+        #[arg(long, value_enum, default_value_t = Mode::Strict)]
+        parse_mode: Mode,
+    },
+    /// Submit the answer to one part of a day's puzzle
+    Submit {
+        #[arg(value_parser = selection::day)]
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Answer to submit, instead of solving the puzzle input
+        #[arg(long)]
+        answer: Option<String>,
+
+        /// Config file with the session cookie and site settings
+        #[arg(long, value_name = "PATH", default_value = config::DEFAULT_PATH)]
+        config: PathBuf,
+
+        /// Log of submitted answers and how they were judged
+        #[arg(long, value_name = "PATH", default_value = submit::DEFAULT_PATH)]
+        guesses: PathBuf,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            parse_mode,
+            jobs: Some(jobs),
+            ..
+        } => parallel::run(&days, part, parse_mode, jobs),
+        Command::Run {
+            days,
+            part,
+            example,
+            parse_mode,
+            bench,
+            jobs: None,
+        } => run(&days, part, example, parse_mode, &bench),
+        Command::Compare { threshold, history } => compare::run(&history, threshold),
+        Command::Verify {
+            days,
+            answers,
+            accept,
+        } => verify::run(&days, &answers, accept),
+        Command::Gen {
+            day,
+            size,
+            seed,
+            output,
+        } => gen(day, size, seed, output),
+        Command::Export {
+            day,
+            example,
+            format,
+            scale,
+            output,
+        } => export::run(day, example, format, scale, &output),
+        Command::Calibrate {
+            example,
+            vocabulary,
+            ignore_case,
+            unicode_digits,
+            format,
+            output,
+        } => {
+            let vocabulary = match ignore_case {
+                true => vocabulary.ignoring_case(),
+                false => vocabulary,
+            };
+            let vocabulary = match unicode_digits {
+                true => vocabulary.with_unicode_digits(),
+                false => vocabulary,
+            };
+            calibrate::run(example, &vocabulary, format, output.as_deref())
+        }
+        Command::Fetch {
+            day,
+            config,
+            cache_dir,
+        } => fetch::run(day, &config, cache_dir),
+        Command::New { day } => scaffold::run(day),
+        Command::Watch { day, parse_mode } => watch::run(day, parse_mode),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            config,
+            guesses,
+        } => submit::run(day, part, answer, &config, &guesses),
+    }
+}
+
+fn run(
+    selection: &Selection,
+    part: Part,
+    example: Option<usize>,
+    mode: Mode,
+    bench: &BenchArgs,
+) -> anyhow::Result<()> {
+    for (index, day) in selection.days().enumerate() {
+        if index > 0 {
+            println!();
+        }
+
+        let day = days::day(day);
+        println!("Day {:02}", day.day);
+
+        match example {
+            Some(number) => {
+                let (example, part) = (day.example)(number, part)?;
+                (day.run)(example.input, part, mode, bench)?;
+            }
+            None => {
+                let input = day.read_input()?;
+                (day.run)(&input, part, mode, bench)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn gen(day: u8, size: usize, seed: u64, output: Option<PathBuf>) -> anyhow::Result<()> {
+    let input = (days::day(day).generate)(size, seed);
+
+    match output {
+        Some(path) => {
+            fs::write(&path, input).with_context(|| format!("could not write {}", path.display()))
+        }
+        None => {
+            print!("{input}");
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{days::Day, selection::Selection};
+use anyhow::bail;
+use common::{
+    bench::{Stage, Timed},
+    cli::Part,
+    parse::Mode,
+};
+use humantime::format_duration;
+use rayon::prelude::*;
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+/// A row of the report: one stage of one day.
+#[derive(Debug)]
+struct Row {
+    day: u8,
+    stage: Option<Stage>,
+    duration: Option<Duration>,
+    result: Result<Option<String>, String>,
+}
+
+/// Solves one day, turning a failure to read its input or a panic anywhere
+/// in its solver into an error row.
+fn solve(day: &Day, part: Part, mode: Mode) -> Vec<Row> {
+    let error = |message| Row {
+        day: day.day,
+        stage: None,
+        duration: None,
+        result: Err(message),
+    };
+
+    let input = match day.read_input() {
+        Ok(input) => input,
+        Err(e) => return vec![error(format!("{e:#}"))],
+    };
+
+    match panic::catch_unwind(AssertUnwindSafe(|| (day.timed)(&input, part, mode))) {
+        Ok(stages) => stages
+            .into_iter()
+            .map(
+                |Timed {
+                     stage,
+                     duration,
+                     result,
+                 }| Row {
+                    day: day.day,
+                    stage: Some(stage),
+                    duration: Some(duration),
+                    result: result.map_err(|e| format!("{e:#}")),
+                },
+            )
+            .collect(),
+        Err(payload) => vec![error(format!("panicked: {}", panic_message(&*payload)))],
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
+}
+
+/// Solves the days on `jobs` threads, in calendar order.
+fn solve_all(days: &[&Day], part: Part, mode: Mode, jobs: usize) -> anyhow::Result<Vec<Row>> {
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?;
+
+    // The panic hook is left alone, as it is shared with every other thread:
+    // a panic is still printed to stderr as it happens, and its payload is
+    // reported in the table, which is only printed once every day is done.
+    let rows = pool.install(|| {
+        days.par_iter()
+            .map(|day| solve(day, part, mode))
+            .collect::<Vec<_>>()
+    });
+
+    Ok(rows.into_iter().flatten().collect())
+}
+
+/// A duration to the microsecond, which is as fine as a single run is worth
+/// reading.
+fn micros(duration: Duration) -> String {
+    let duration = Duration::from_micros(duration.as_micros() as u64);
+    format_duration(duration).to_string()
+}
+
+/// Solves every selected day concurrently and prints one table of the
+/// results. Fails after printing if any stage failed.
+pub fn run(selection: &Selection, part: Part, mode: Mode, jobs: usize) -> anyhow::Result<()> {
+    let days = selection.days().map(crate::days::day).collect::<Vec<_>>();
+    let rows = solve_all(&days, part, mode, jobs)?;
+
+    println!(
+        "{:<5}{:<7}{:>20}{:>22}  Status",
+        "Day", "Stage", "Answer", "Duration"
+    );
+
+    let mut failures = 0;
+
+    for row in &rows {
+        let (answer, status) = match &row.result {
+            Ok(answer) => (answer.as_deref().unwrap_or("-"), String::from("ok")),
+            Err(e) => {
+                failures += 1;
+                ("-", format!("error: {e}"))
+            }
+        };
+
+        println!(
+            "{:<5}{:<7}{:>20}{:>22}  {}",
+            row.day,
+            row.stage
+                .map_or(String::from("-"), |stage| stage.key().to_string()),
+            answer,
+            row.duration.map_or(String::from("-"), micros),
+            status
+        );
+    }
+
+    if failures > 0 {
+        bail!("{failures} stage(s) failed");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+    use common::bench;
+
+    fn fake(day: u8, timed: fn(&str, Part, Mode) -> Vec<Timed>) -> Day {
+        Day {
+            day,
+            input: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"),
+            timed,
+            ..DAYS[0]
+        }
+    }
+
+    #[test]
+    fn isolates_failures() {
+        let ok = fake(1, |_, _, _| {
+            vec![Timed {
+                stage: Stage::Part1,
+                duration: Duration::ZERO,
+                result: Ok(Some(String::from("42"))),
+            }]
+        });
+        let panics = fake(2, |_, _, _| panic!("no node 'ZZZ'"));
+        let missing = Day {
+            input: "/nonexistent/day03.txt",
+            ..fake(3, bench::timed::<day03::Day03>)
+        };
+        let fails = fake(4, bench::timed::<day04::Day04>);
+
+        let rows = solve_all(
+            &[&ok, &panics, &missing, &fails],
+            Part::Both,
+            Mode::Strict,
+            2,
+        )
+        .unwrap();
+
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows.iter().map(|r| r.day).collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!(rows[0].result.as_ref().unwrap().as_deref(), Some("42"));
+        assert_eq!(rows[1].result, Err(String::from("panicked: no node 'ZZZ'")));
+        assert!(rows[2]
+            .result
+            .as_ref()
+            .unwrap_err()
+            .starts_with("could not read"));
+        assert_eq!(rows[3].stage, Some(Stage::Parse));
+        assert!(rows[3].result.is_err());
+    }
+}
@@ -0,0 +1,239 @@
+use crate::selection::LAST_DAY;
+use anyhow::{bail, Context};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Root of the workspace that new days are added to.
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const TEMPLATES: [(&str, &str); 4] = [
+    (
+        "day{nn}/Cargo.toml",
+        include_str!("../templates/Cargo.toml"),
+    ),
+    ("day{nn}/src/main.rs", include_str!("../templates/main.rs")),
+    ("day{nn}/src/lib.rs", include_str!("../templates/lib.rs")),
+    (
+        "fuzz/fuzz_targets/day{nn}.rs",
+        include_str!("../templates/fuzz.rs"),
+    ),
+];
+
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("{nn}", &format!("{day:02}"))
+        .replace("{day}", &day.to_string())
+}
+
+/// Inserts `line` after the line that is exactly `after`.
+fn insert(file: &mut (PathBuf, String), after: &str, line: &str) -> anyhow::Result<()> {
+    let (path, text) = file;
+
+    let start = text
+        .match_indices(after)
+        .map(|(start, _)| start)
+        .find(|&start| {
+            text[..start].ends_with('\n') && text[start + after.len()..].starts_with('\n')
+        })
+        .with_context(|| format!("could not find `{after}` in {}", path.display()))?;
+
+    text.insert_str(start + after.len() + 1, &format!("{line}\n"));
+    Ok(())
+}
+
+fn replace(file: &mut (PathBuf, String), from: &str, to: &str) -> anyhow::Result<()> {
+    let (path, text) = file;
+
+    if !text.contains(from) {
+        bail!("could not find `{from}` in {}", path.display());
+    }
+
+    *text = text.replacen(from, to, 1);
+    Ok(())
+}
+
+/// Creates a crate for `day` in the workspace at `root`, and registers it
+/// with the workspace, the runner and the fuzz targets next to the day
+/// before it. Returns the files it created.
+pub fn scaffold(root: &Path, day: u8) -> anyhow::Result<Vec<PathBuf>> {
+    let dir = root.join(format!("day{day:02}"));
+
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    let read = |path: &str| -> anyhow::Result<(PathBuf, String)> {
+        let path = root.join(path);
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        Ok((path, text))
+    };
+
+    let mut workspace = read("Cargo.toml")?;
+    let mut runner = read("aoc/Cargo.toml")?;
+    let mut days = read("aoc/src/days.rs")?;
+    let mut fuzz = read("fuzz/Cargo.toml")?;
+
+    // Everything is edited in memory first, so a file that does not look as
+    // expected leaves the workspace untouched.
+    let (prev, nn) = (day - 1, format!("{day:02}"));
+    let prev_nn = format!("{prev:02}");
+
+    insert(
+        &mut workspace,
+        &format!("    \"day{prev_nn}\","),
+        &format!("    \"day{nn}\","),
+    )?;
+    insert(
+        &mut workspace,
+        &format!("day{prev_nn} = {{ path = \"day{prev_nn}\" }}"),
+        &format!("day{nn} = {{ path = \"day{nn}\" }}"),
+    )?;
+    insert(
+        &mut runner,
+        &format!("day{prev_nn}.workspace = true"),
+        &format!("day{nn}.workspace = true"),
+    )?;
+    insert(
+        &mut days,
+        &format!("    Day::new::<day{prev_nn}::Day{prev_nn}>(),"),
+        &format!("    Day::new::<day{nn}::Day{nn}>(),"),
+    )?;
+    replace(
+        &mut days,
+        &format!("[Day; {prev}]"),
+        &format!("[Day; {day}]"),
+    )?;
+    insert(
+        &mut fuzz,
+        &format!("day{prev_nn} = {{ path = \"../day{prev_nn}\" }}"),
+        &format!("day{nn} = {{ path = \"../day{nn}\" }}"),
+    )?;
+
+    fuzz.1.push_str(&format!(
+        "\n[[bin]]\nname = \"day{nn}\"\npath = \"fuzz_targets/day{nn}.rs\"\n\
+         test = false\ndoc = false\nbench = false\n"
+    ));
+
+    let mut created = Vec::new();
+
+    let files = TEMPLATES
+        .iter()
+        .map(|(path, template)| (fill(path, day), fill(template, day)))
+        .chain([(format!("day{nn}/examples/1.txt"), String::new())]);
+
+    for (path, text) in files {
+        let path = root.join(path);
+        let parent = path.parent().unwrap();
+
+        fs::create_dir_all(parent)
+            .with_context(|| format!("could not create {}", parent.display()))?;
+        fs::write(&path, text).with_context(|| format!("could not write {}", path.display()))?;
+        created.push(path);
+    }
+
+    for (path, text) in [workspace, runner, days, fuzz] {
+        fs::write(&path, text).with_context(|| format!("could not write {}", path.display()))?;
+    }
+
+    Ok(created)
+}
+
+pub fn run(day: u8) -> anyhow::Result<()> {
+    if day != LAST_DAY + 1 {
+        bail!(
+            "the next day to add is day {}, days are added in order",
+            LAST_DAY + 1
+        );
+    }
+
+    let root = Path::new(ROOT);
+
+    for path in scaffold(root, day)? {
+        let path = path.strip_prefix(root).unwrap_or(&path);
+        println!("Created {}", path.display());
+    }
+
+    println!("Day {day:02} is registered; run `aoc fetch {day}` to download its puzzle input");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    /// A copy of the files of the workspace that registering a day edits.
+    fn workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        for path in [
+            "Cargo.toml",
+            "aoc/Cargo.toml",
+            "aoc/src/days.rs",
+            "fuzz/Cargo.toml",
+        ] {
+            let target = root.join(path);
+            fs::create_dir_all(target.parent().unwrap()).unwrap();
+            fs::copy(Path::new(ROOT).join(path), target).unwrap();
+        }
+
+        root
+    }
+
+    #[test]
+    fn registers_the_day() {
+        let root = workspace("registers");
+        let created = scaffold(&root, LAST_DAY + 1).unwrap();
+        let nn = format!("{:02}", LAST_DAY + 1);
+
+        assert_eq!(created.len(), 5);
+        assert!(created.iter().all(|path| path.exists()));
+
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+
+        let lib = read(&format!("day{nn}/src/lib.rs"));
+        assert!(lib.contains(&format!("pub struct Day{nn};")));
+        assert!(lib.contains(&format!("const DAY: u8 = {};", LAST_DAY + 1)));
+        assert!(!lib.contains("{nn}") && !lib.contains("{day}"));
+
+        let workspace = read("Cargo.toml");
+        assert!(workspace.contains(&format!("    \"day{nn}\",\n")));
+        assert!(workspace.contains(&format!("day{nn} = {{ path = \"day{nn}\" }}\n")));
+
+        let days = read("aoc/src/days.rs");
+        assert!(days.contains(&format!("[Day; {}]", LAST_DAY + 1)));
+        assert!(days.contains(&format!("    Day::new::<day{nn}::Day{nn}>(),\n];")));
+
+        assert!(read("aoc/Cargo.toml").contains(&format!("day{nn}.workspace = true")));
+        assert!(read("fuzz/Cargo.toml").contains(&format!("name = \"day{nn}\"")));
+
+        let res = scaffold(&root, LAST_DAY + 1).unwrap_err();
+        assert!(res.to_string().ends_with("already exists"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn leaves_the_workspace_alone_on_failure() {
+        let root = workspace("failure");
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "pub const DAYS: [Day; 0] = [];\n",
+        )
+        .unwrap();
+
+        assert!(scaffold(&root, LAST_DAY + 1).is_err());
+        assert!(!root.join(format!("day{:02}", LAST_DAY + 1)).exists());
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            fs::read_to_string(Path::new(ROOT).join("Cargo.toml")).unwrap()
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
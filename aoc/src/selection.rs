@@ -0,0 +1,130 @@
+use crate::days::DAYS;
+use anyhow::{anyhow, bail};
+use std::{ops::RangeInclusive, str::FromStr};
+
+pub const FIRST_DAY: u8 = 1;
+pub const LAST_DAY: u8 = DAYS.len() as u8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selection(RangeInclusive<u8>);
+
+impl Selection {
+    pub fn days(&self) -> RangeInclusive<u8> {
+        self.0.clone()
+    }
+}
+
+impl FromStr for Selection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let range = if s == "all" {
+            FIRST_DAY..=LAST_DAY
+        } else if let Some((start, end)) = s.split_once("..=") {
+            day(start)?..=day(end)?
+        } else if let Some((start, end)) = s.split_once("..") {
+            // The end is the day after the last one, so it can be one past
+            // the calendar.
+            let end = end
+                .trim()
+                .parse::<u8>()
+                .map_err(|_| anyhow!("'{end}' is not a day number"))?;
+
+            if !(1..=26).contains(&end) {
+                bail!("there is no day {end} to end a range at (expected 1..=26)");
+            }
+
+            if end <= FIRST_DAY {
+                bail!("empty day range '{s}'");
+            }
+
+            day(start)?..=solved(end - 1)?
+        } else {
+            let day = day(s)?;
+            day..=day
+        };
+
+        if range.is_empty() {
+            bail!("empty day range '{s}'");
+        }
+
+        Ok(Self(range))
+    }
+}
+
+/// Any day of the Advent calendar, whether it is solved or not.
+pub fn calendar_day(s: &str) -> anyhow::Result<u8> {
+    let day = s
+        .trim()
+        .parse::<u8>()
+        .map_err(|_| anyhow!("'{s}' is not a day number"))?;
+
+    if (1..=25).contains(&day) {
+        Ok(day)
+    } else {
+        bail!("there is no day {day} (expected 1..=25)")
+    }
+}
+
+pub fn day(s: &str) -> anyhow::Result<u8> {
+    solved(calendar_day(s)?)
+}
+
+fn solved(day: u8) -> anyhow::Result<u8> {
+    if (FIRST_DAY..=LAST_DAY).contains(&day) {
+        Ok(day)
+    } else {
+        bail!("day {day} is not solved yet (expected {FIRST_DAY}..={LAST_DAY})")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_day() {
+        let res = "7".parse::<Selection>().unwrap();
+        assert_eq!(res.days(), 7..=7);
+    }
+
+    #[test]
+    fn all_days() {
+        let res = "all".parse::<Selection>().unwrap();
+        assert_eq!(res.days(), FIRST_DAY..=LAST_DAY);
+    }
+
+    #[test]
+    fn ranges() {
+        let res = "3..=5".parse::<Selection>().unwrap();
+        assert_eq!(res.days(), 3..=5);
+
+        let res = "3..5".parse::<Selection>().unwrap();
+        assert_eq!(res.days(), 3..=4);
+
+        let res = format!("3..{}", LAST_DAY + 1).parse::<Selection>().unwrap();
+        assert_eq!(res.days(), 3..=LAST_DAY);
+        assert_eq!("3..12".parse::<Selection>().unwrap().days(), 3..=11);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!("0".parse::<Selection>().is_err());
+        assert!("12".parse::<Selection>().is_err());
+        assert!("5..=3".parse::<Selection>().is_err());
+        assert!("3..3".parse::<Selection>().is_err());
+        assert!("3..1".parse::<Selection>().is_err());
+        assert!(format!("3..{}", LAST_DAY + 2).parse::<Selection>().is_err());
+        assert!("3..27".parse::<Selection>().is_err());
+        assert!("seven".parse::<Selection>().is_err());
+    }
+
+    #[test]
+    fn calendar_days() {
+        assert_eq!(calendar_day("25").unwrap(), 25);
+        assert!(calendar_day("0").is_err());
+        assert!(calendar_day("26").is_err());
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    client::{Client, Outcome, Response},
+    config::Config,
+    days,
+};
+use anyhow::{bail, Context};
+use common::cli::Part;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+/// Log of every answer submitted, at the root of the workspace.
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../guesses.toml");
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    /// Hash of the puzzle input the answer was computed from.
+    pub input: String,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuessLog {
+    #[serde(default, rename = "guess")]
+    guesses: Vec<Guess>,
+}
+
+impl GuessLog {
+    /// Loads the log, treating a missing file as empty.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match common::read_optional(path)? {
+            Some(data) => {
+                toml::from_str(&data).with_context(|| format!("could not parse {}", path.display()))
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let data = toml::to_string(self)?;
+        fs::write(path, data).with_context(|| format!("could not write {}", path.display()))
+    }
+
+    pub fn record(&mut self, guess: Guess) {
+        self.guesses.push(guess);
+    }
+
+    /// Fails if the log already tells whether `answer` is right: the part is
+    /// solved, the same answer was judged before, or an earlier too high or
+    /// too low guess rules it out.
+    pub fn check(&self, day: u8, part: u8, input: &str, answer: &str) -> anyhow::Result<()> {
+        let guesses = self
+            .guesses
+            .iter()
+            .filter(|g| g.day == day && g.part == part && g.input == input)
+            .filter(|g| g.outcome != Outcome::RateLimited);
+
+        let value = answer.parse::<i128>().ok();
+
+        for guess in guesses {
+            if guess.outcome == Outcome::Correct {
+                bail!(
+                    "day {day} part {part} is already solved, the answer was {}",
+                    guess.answer
+                );
+            }
+
+            if guess.answer == answer {
+                bail!("{answer} was already submitted, and was {}", guess.outcome);
+            }
+
+            let Some((value, bound)) = value.zip(guess.answer.parse::<i128>().ok()) else {
+                continue;
+            };
+
+            match guess.outcome {
+                Outcome::TooHigh if value >= bound => {
+                    bail!("{answer} cannot be right, {bound} was already too high")
+                }
+                Outcome::TooLow if value <= bound => {
+                    bail!("{answer} cannot be right, {bound} was already too low")
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Submits an answer unless the log rules it out, and logs the outcome.
+pub fn submit(
+    client: &Client,
+    log: &mut GuessLog,
+    day: u8,
+    part: u8,
+    input: &str,
+    answer: &str,
+) -> anyhow::Result<Response> {
+    log.check(day, part, input, answer)?;
+
+    let response = client.submit(day, part, answer)?;
+
+    log.record(Guess {
+        day,
+        part,
+        input: input.to_string(),
+        answer: answer.to_string(),
+        outcome: response.outcome,
+    });
+
+    Ok(response)
+}
+
+pub fn run(
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    config: &Path,
+    guesses: &Path,
+) -> anyhow::Result<()> {
+    let config = Config::load(config)?;
+    let mut log = GuessLog::load(guesses)?;
+
+    let day = days::day(day);
+    let input = day.read_input()?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let answers = match part {
+                1 => (day.solve)(&input, Part::One)?.part1,
+                _ => (day.solve)(&input, Part::Two)?.part2,
+            };
+            answers.context("the solver gave no answer")?
+        }
+    };
+
+    let input = common::input_hash(&input);
+    let response = submit(
+        &Client::new(&config),
+        &mut log,
+        day.day,
+        part,
+        &input,
+        &answer,
+    )?;
+    log.save(guesses)?;
+
+    print!(
+        "Day {:02} part {part}: {answer} is {}",
+        day.day, response.outcome
+    );
+
+    match response.wait {
+        Some(wait) => println!(", try again in {}", humantime::format_duration(wait)),
+        None => println!(),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::Server;
+
+    fn guess(answer: &str, outcome: Outcome) -> Guess {
+        Guess {
+            day: 7,
+            part: 1,
+            input: String::from("aa"),
+            answer: answer.to_string(),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn rules_out_answers() {
+        let mut log = GuessLog::default();
+        log.record(guess("100", Outcome::TooHigh));
+        log.record(guess("20", Outcome::TooLow));
+        log.record(guess("50", Outcome::Wrong));
+        log.record(guess("60", Outcome::RateLimited));
+
+        assert!(log.check(7, 1, "aa", "99").is_ok());
+        assert!(log.check(7, 1, "aa", "60").is_ok());
+        assert!(log.check(7, 1, "aa", "100").is_err());
+        assert!(log.check(7, 1, "aa", "1000").is_err());
+        assert!(log.check(7, 1, "aa", "20").is_err());
+        assert!(log.check(7, 1, "aa", "-5").is_err());
+        assert!(log.check(7, 1, "aa", "50").is_err());
+
+        // Guesses about another part or input say nothing.
+        assert!(log.check(7, 2, "aa", "1000").is_ok());
+        assert!(log.check(7, 1, "bb", "1000").is_ok());
+
+        log.record(guess("70", Outcome::Correct));
+        let res = log.check(7, 1, "aa", "71").unwrap_err();
+        assert_eq!(
+            res.to_string(),
+            "day 7 part 1 is already solved, the answer was 70"
+        );
+
+        let res = toml::from_str::<GuessLog>(&toml::to_string(&log).unwrap()).unwrap();
+        assert_eq!(res, log);
+    }
+
+    #[test]
+    fn submits_once_per_answer() {
+        let server = Server::start(vec![
+            (200, "That's not the right answer; your answer is too high."),
+            (
+                200,
+                "You gave an answer too recently. You have 45s left to wait.",
+            ),
+        ]);
+        let client = Client::new(&Config {
+            session: Some(String::from("abc")),
+            base_url: Some(server.url.clone()),
+            ..Config::default()
+        });
+        let mut log = GuessLog::default();
+
+        let res = submit(&client, &mut log, 7, 1, "aa", "6440").unwrap();
+        assert_eq!(res.outcome, Outcome::TooHigh);
+
+        assert!(submit(&client, &mut log, 7, 1, "aa", "6440").is_err());
+        assert!(submit(&client, &mut log, 7, 1, "aa", "7000").is_err());
+
+        let res = submit(&client, &mut log, 7, 1, "aa", "6000").unwrap();
+        assert_eq!(res.outcome, Outcome::RateLimited);
+
+        // Answers that were never judged can be tried again.
+        assert!(log.check(7, 1, "aa", "6000").is_ok());
+        assert_eq!(server.requests().len(), 2);
+        assert_eq!(log.guesses.len(), 2);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{
+    days::{self, Day},
+    selection::Selection,
+};
+use anyhow::bail;
+use common::{
+    answers::{AnswerFile, Answers},
+    cli::Part,
+    input_hash,
+};
+use std::{fmt::Display, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Accepted,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail => write!(f, "FAIL"),
+            Self::Missing => write!(f, "missing"),
+            Self::Accepted => write!(f, "accepted"),
+        }
+    }
+}
+
+/// A row of the table: one part of a day, or a day that could not be solved.
+#[derive(Debug, Clone, PartialEq)]
+enum Row {
+    Part {
+        day: u8,
+        part: u8,
+        input: String,
+        expected: Option<String>,
+        actual: Option<String>,
+        status: Status,
+    },
+    Error {
+        day: u8,
+        message: String,
+    },
+}
+
+impl Display for Row {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Part {
+                day,
+                part,
+                input,
+                expected,
+                actual,
+                status,
+            } => write!(
+                f,
+                "{:<5}{:<6}{:<18}{:>20}{:>20}  {}",
+                day,
+                part,
+                input,
+                expected.as_deref().unwrap_or("-"),
+                actual.as_deref().unwrap_or("-"),
+                status
+            ),
+            Self::Error { day, message } => write!(
+                f,
+                "{:<5}{:<6}{:<18}{:>20}{:>20}  ERROR: {message}",
+                day, "-", "-", "-", "-"
+            ),
+        }
+    }
+}
+
+/// The hash of a day's input and its answers.
+fn solve(day: &Day) -> anyhow::Result<(String, Answers)> {
+    let input = day.read_input()?;
+    let answers = (day.solve)(&input, Part::Both)?;
+
+    Ok((input_hash(&input), answers))
+}
+
+/// Solves the days and checks every answer against the answers file at
+/// `path`. With `accept`, answers that are not confirmed yet are recorded in
+/// the file instead. A day that cannot be solved does not stop the others.
+fn verify(days: &[&Day], path: &Path, accept: bool) -> anyhow::Result<Vec<Row>> {
+    let mut file = AnswerFile::load(path)?;
+    let mut changed = false;
+    let mut rows = Vec::new();
+
+    for day in days {
+        let (hash, actual) = match solve(day) {
+            Ok(solved) => solved,
+            Err(e) => {
+                rows.push(Row::Error {
+                    day: day.day,
+                    message: format!("{e:#}"),
+                });
+                continue;
+            }
+        };
+
+        let expected = file.get(day.day, &hash).cloned().unwrap_or_default();
+
+        let parts = [
+            (1, expected.part1, &actual.part1),
+            (2, expected.part2, &actual.part2),
+        ];
+
+        for (part, expected, actual) in parts {
+            let status = match (&expected, actual) {
+                (Some(expected), Some(actual)) if expected == actual => Status::Pass,
+                (Some(_), _) => Status::Fail,
+                (None, _) if accept => Status::Accepted,
+                (None, _) => Status::Missing,
+            };
+
+            rows.push(Row::Part {
+                day: day.day,
+                part,
+                input: hash.clone(),
+                expected,
+                actual: actual.clone(),
+                status,
+            });
+        }
+
+        if accept {
+            changed |= file.accept(day.day, &hash, &actual);
+        }
+    }
+
+    if changed {
+        file.save(path)?;
+    }
+
+    Ok(rows)
+}
+
+/// Solves the selected days and checks every answer against the answers file.
+/// With `accept`, answers that are not confirmed yet are recorded instead.
+pub fn run(selection: &Selection, path: &Path, accept: bool) -> anyhow::Result<()> {
+    let days = selection.days().map(days::day).collect::<Vec<_>>();
+
+    println!(
+        "{:<5}{:<6}{:<18}{:>20}{:>20}  Status",
+        "Day", "Part", "Input", "Expected", "Actual"
+    );
+
+    let rows = verify(&days, path, accept)?;
+    let (mut failures, mut errors) = (0, 0);
+
+    for row in &rows {
+        println!("{row}");
+
+        match row {
+            Row::Part {
+                status: Status::Fail,
+                ..
+            } => failures += 1,
+            Row::Error { .. } => errors += 1,
+            Row::Part { .. } => {}
+        }
+    }
+
+    match (failures, errors) {
+        (0, 0) => Ok(()),
+        (_, 0) => bail!("{failures} answer(s) differ from {}", path.display()),
+        (0, _) => bail!("{errors} day(s) could not be solved"),
+        _ => bail!(
+            "{failures} answer(s) differ from {}, and {errors} day(s) could not be solved",
+            path.display()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+    use anyhow::anyhow;
+    use std::{env, fs, path::PathBuf};
+
+    const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+
+    fn fake(day: u8, solve: fn(&str, Part) -> anyhow::Result<Answers>) -> Day {
+        Day {
+            day,
+            input: INPUT,
+            solve,
+            ..DAYS[0]
+        }
+    }
+
+    fn solved(_: &str, _: Part) -> anyhow::Result<Answers> {
+        Ok(Answers {
+            part1: Some(String::from("6440")),
+            part2: Some(String::from("5905")),
+        })
+    }
+
+    fn answers_file(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-verify-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir.join("answers.toml")
+    }
+
+    fn statuses(rows: &[Row]) -> Vec<Option<Status>> {
+        rows.iter()
+            .map(|row| match row {
+                Row::Part { status, .. } => Some(*status),
+                Row::Error { .. } => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn checks_answers() {
+        let path = answers_file("checks");
+        let hash = input_hash(&fs::read_to_string(INPUT).unwrap());
+
+        let mut file = AnswerFile::default();
+        file.accept(
+            7,
+            &hash,
+            &Answers {
+                part1: Some(String::from("6440")),
+                part2: Some(String::from("5906")),
+            },
+        );
+        file.save(&path).unwrap();
+
+        let confirmed = fake(7, solved);
+        let unconfirmed = fake(8, solved);
+        let missing = Day {
+            input: "/nonexistent/day09.txt",
+            ..fake(9, solved)
+        };
+        let fails = fake(10, |_, _| Err(anyhow!("no loop through 'S'")));
+
+        let rows = verify(&[&confirmed, &unconfirmed, &missing, &fails], &path, false).unwrap();
+
+        assert_eq!(
+            statuses(&rows),
+            [
+                Some(Status::Pass),
+                Some(Status::Fail),
+                Some(Status::Missing),
+                Some(Status::Missing),
+                None,
+                None,
+            ]
+        );
+        assert_eq!(rows[1].to_string().split_whitespace().nth(3), Some("5906"));
+
+        let Row::Error { day, message } = &rows[4] else {
+            unreachable!()
+        };
+        assert_eq!(*day, 9);
+        assert!(message.starts_with("could not read /nonexistent/day09.txt"));
+        assert_eq!(
+            rows[5],
+            Row::Error {
+                day: 10,
+                message: String::from("no loop through 'S'")
+            }
+        );
+
+        // Nothing is written without `accept`.
+        assert_eq!(AnswerFile::load(&path).unwrap(), file);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn accepts_answers() {
+        let path = answers_file("accepts");
+        let hash = input_hash(&fs::read_to_string(INPUT).unwrap());
+        let day = fake(8, solved);
+
+        let rows = verify(&[&day], &path, true).unwrap();
+        assert_eq!(
+            statuses(&rows),
+            [Some(Status::Accepted), Some(Status::Accepted)]
+        );
+
+        let file = AnswerFile::load(&path).unwrap();
+        assert_eq!(file.get(8, &hash), Some(&solved("", Part::Both).unwrap()));
+
+        let rows = verify(&[&day], &path, true).unwrap();
+        assert_eq!(statuses(&rows), [Some(Status::Pass), Some(Status::Pass)]);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
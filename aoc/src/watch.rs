@@ -0,0 +1,244 @@
+use crate::days::{self, Day};
+use common::{
+    bench::Timed,
+    cli::{self, Input, Part},
+    parse::Mode,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+const POLL: Duration = Duration::from_millis(250);
+
+/// Answers of every part of every file, keyed by file and part, as in
+/// `example 2, part 1`. Failures are kept as their error message.
+type Answers = BTreeMap<String, String>;
+
+/// Last modification times of the files, `None` for one that is missing.
+fn modified(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// The files a day is solved from: the input it reads, which is the fetched
+/// one when the day has none of its own, and its examples.
+fn files(day: &Day, cache_dir: &Path) -> Vec<PathBuf> {
+    [cli::input_path(day.input, cache_dir)]
+        .into_iter()
+        .chain(
+            day.examples
+                .iter()
+                .map(|example| PathBuf::from(example.path)),
+        )
+        .collect()
+}
+
+fn record(answers: &mut Answers, file: &str, stages: Vec<Timed>) {
+    for Timed { stage, result, .. } in stages {
+        let answer = match result {
+            Ok(Some(answer)) => answer,
+            Ok(None) => continue,
+            Err(e) => format!("error: {e:#}"),
+        };
+
+        answers.insert(
+            format!("{file}, {}", stage.to_string().to_lowercase()),
+            answer,
+        );
+    }
+}
+
+/// Solves the day's input and examples as they are on disk now.
+fn solve(day: &Day, mode: Mode) -> Answers {
+    let mut answers = Answers::new();
+
+    match day.read_input() {
+        Ok(input) => record(&mut answers, "input", (day.timed)(&input, Part::Both, mode)),
+        Err(e) => {
+            answers.insert(String::from("input"), format!("error: {e:#}"));
+        }
+    }
+
+    for (index, example) in day.examples.iter().enumerate() {
+        let file = format!("example {}", index + 1);
+
+        match Input::Default.read(example.path) {
+            Ok(input) => {
+                // Only the parts the example has an answer for, as the other
+                // part often fails on an example written for one part.
+                let part = example.part(Part::Both).unwrap_or(Part::Both);
+                record(&mut answers, &file, (day.timed)(&input, part, mode));
+            }
+            Err(e) => {
+                answers.insert(file, format!("error: {e:#}"));
+            }
+        }
+    }
+
+    answers
+}
+
+/// One line per answer, noting what it was on the previous run if that
+/// differs. Answers that are gone, such as the parts of a file that no
+/// longer parses, are listed as `-`.
+fn diff(previous: Option<&Answers>, current: &Answers) -> Vec<String> {
+    let empty = Answers::new();
+    let keys = current
+        .keys()
+        .chain(previous.unwrap_or(&empty).keys())
+        .collect::<BTreeSet<_>>();
+    let width = keys.iter().map(|key| key.len()).max().unwrap_or(0);
+
+    let mut lines = Vec::new();
+
+    for key in keys {
+        let answer = current.get(key).map_or("-", String::as_str);
+        let old = previous.map(|previous| previous.get(key).map_or("-", String::as_str));
+
+        // Parse errors point into the input on the lines after the message,
+        // which are kept below the note.
+        let (first, rest) = answer.split_once('\n').unwrap_or((answer, ""));
+        let line = format!("{key:<width$}  {first}");
+
+        lines.push(match old {
+            Some(old) if old != answer => {
+                let old = old.lines().next().unwrap_or_default();
+                format!("{line}  (was {old})")
+            }
+            _ => line,
+        });
+        lines.extend(rest.lines().map(str::to_string));
+    }
+
+    lines
+}
+
+/// Solves a day, then again every time its input or one of its examples
+/// changes on disk, until interrupted.
+pub fn run(day: u8, mode: Mode) -> anyhow::Result<()> {
+    let day = days::day(day);
+    let files = files(day, &days::cache_dir()?);
+
+    println!("Watching day {:02}, stop with Ctrl-C:", day.day);
+
+    for path in &files {
+        println!("  {}", path.display());
+    }
+
+    // The day's own input is watched even while the fetched one is solved,
+    // as creating it changes which file is read.
+    let mut paths = files;
+    if paths[0] != Path::new(day.input) {
+        paths.push(PathBuf::from(day.input));
+    }
+
+    let mut seen = modified(&paths);
+    let mut previous = None;
+
+    loop {
+        let answers = solve(day, mode);
+
+        println!();
+        for line in diff(previous.as_ref(), &answers) {
+            println!("{line}");
+        }
+        previous = Some(answers);
+
+        // Editors often save in more than one write, so wait for the files
+        // to settle before solving again.
+        loop {
+            thread::sleep(POLL);
+            let now = modified(&paths);
+
+            if now != seen {
+                thread::sleep(POLL);
+                seen = modified(&paths);
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(entries: &[(&str, &str)]) -> Answers {
+        entries
+            .iter()
+            .map(|(key, answer)| (key.to_string(), answer.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let previous = answers(&[("input, part 1", "142"), ("input, part 2", "281")]);
+        let current = answers(&[
+            ("example 1, part 1", "142"),
+            ("input, part 1", "142"),
+            ("input, part 2", "280"),
+        ]);
+
+        assert_eq!(
+            diff(Some(&previous), &current),
+            [
+                "example 1, part 1  142  (was -)",
+                "input, part 1      142",
+                "input, part 2      280  (was 281)",
+            ]
+        );
+        assert_eq!(diff(None, &current)[2], "input, part 2      280");
+
+        let current = answers(&[("input, parse", "error: line 2\n  1 x\n    ^")]);
+        assert_eq!(
+            diff(Some(&previous), &current),
+            [
+                "input, parse   error: line 2  (was -)",
+                "  1 x",
+                "    ^",
+                "input, part 1  -  (was 142)",
+                "input, part 2  -  (was 281)",
+            ]
+        );
+    }
+
+    #[test]
+    fn watches_fetched_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let own = days::day(9);
+        assert_eq!(files(own, &dir)[0], Path::new(own.input));
+        assert_eq!(files(own, &dir).len(), 1 + own.examples.len());
+
+        let fetched = Day {
+            input: "/nonexistent/day09.txt",
+            ..days::DAYS[8]
+        };
+        assert_eq!(
+            files(&fetched, &dir)[0],
+            Path::new("/nonexistent/day09.txt")
+        );
+
+        fs::write(dir.join("day09.txt"), "0 3 6 9\n").unwrap();
+        assert_eq!(files(&fetched, &dir)[0], dir.join("day09.txt"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn solves_input_and_examples() {
+        let answers = solve(days::day(1), Mode::Strict);
+
+        assert_eq!(answers["example 1, part 1"], "142");
+        assert_eq!(answers["example 2, part 2"], "281");
+        assert!(!answers.contains_key("example 1, part 2"));
+        assert!(answers.contains_key("input, part 1"));
+    }
+}
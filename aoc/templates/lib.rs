@@ -0,0 +1,98 @@
+//! Day {day}: TODO title. TODO one line on the puzzle.
+
+use anyhow::bail;
+use common::{
+    example,
+    example::Example,
+    generate::Rng,
+    parse::{parse_lines, parse_lines_lenient, IResult, ParseError},
+    Solution,
+};
+use nom::{
+    character::complete::{space0, u64},
+    combinator::map,
+    error::context,
+    multi::many1,
+    sequence::tuple,
+};
+
+pub struct Day{nn};
+
+impl Solution for Day{nn} {
+    const DAY: u8 = {day};
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day{nn}.txt");
+    // Paste the example from the puzzle statement into examples/1.txt, and
+    // fill in its answers as each part is solved.
+    const EXAMPLES: &'static [Example] = &[example!("1.txt", None, None)];
+
+    type Parsed = Vec<Vec<u64>>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_lines(input, line)?)
+    }
+
+    fn parse_lenient(input: &str) -> anyhow::Result<(Self::Parsed, Vec<ParseError>)> {
+        Ok(parse_lines_lenient(input, line))
+    }
+
+    fn part1(_lines: &Self::Parsed) -> anyhow::Result<u64> {
+        bail!("part 1 is not solved yet")
+    }
+
+    fn part2(_lines: &Self::Parsed) -> anyhow::Result<u64> {
+        bail!("part 2 is not solved yet")
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+fn line(input: &str) -> IResult<&str, Vec<u64>> {
+    let number = context("a number", u64);
+    many1(map(tuple((number, space0)), |(num, _)| num))(input)
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut lines = Vec::new();
+
+    for _ in 0..size.max(1) {
+        let values = (0..rng.range(1..=10))
+            .map(|_| rng.range(0..=99u64).to_string())
+            .collect::<Vec<_>>();
+
+        lines.push(values.join(" "));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day{nn}>();
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day{nn}>();
+    }
+
+    // Enable once both parts are solved.
+    // #[test]
+    // fn generated_inputs() {
+    //     common::generate::check::<Day{nn}>();
+    // }
+
+    #[test]
+    fn parse_errors() {
+        let res = Day{nn}::parse("1 2 3\n4 x 6").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (2, 3));
+        assert_eq!(res.expected, "end of line");
+    }
+}
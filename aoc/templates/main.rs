@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    common::main::<day{nn}::Day{nn}>()
+}
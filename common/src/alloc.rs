@@ -0,0 +1,161 @@
+//! A global allocator that counts allocations, for reporting what each
+//! stage of a solver allocates. It is only installed with the `alloc-stats`
+//! feature, as in `cargo run --release -p aoc --features alloc-stats -- run 3
+//! --bench 10`, or `--features common/alloc-stats` for a day's own binary.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Whether the counting allocator is installed in this build.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting what goes through it.
+pub struct Counting;
+
+impl Counting {
+    fn allocated(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn freed(size: usize) {
+        LIVE.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::freed(layout.size());
+    }
+
+    // A reallocation counts as a new allocation of the new size, as that is
+    // what growing a `Vec` costs when it cannot be done in place. While it
+    // moves, the old and new blocks are both live, which the peak includes.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::allocated(new_size);
+        let new = System.realloc(ptr, layout, new_size);
+
+        match new.is_null() {
+            true => {
+                ALLOCATIONS.fetch_sub(1, Ordering::Relaxed);
+                ALLOCATED.fetch_sub(new_size, Ordering::Relaxed);
+                Self::freed(new_size);
+            }
+            false => Self::freed(layout.size()),
+        }
+
+        new
+    }
+}
+
+/// What a stage allocated.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Usage {
+    pub allocations: usize,
+    /// Bytes allocated in total, including what was freed again.
+    pub bytes: usize,
+    /// Most bytes live at once, above what was live when the stage started.
+    pub peak: usize,
+}
+
+/// Runs `f`, counting what it allocates. Allocations on other threads are
+/// counted too, so stages should be measured one at a time.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let res = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+
+    (res, usage)
+}
+
+/// A byte count in binary units, like `1.5 KiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations() {
+        let layout = Layout::from_size_align(4096, 8).unwrap();
+
+        // Called directly, so this works whether or not the allocator is
+        // installed.
+        let (_, usage) = measure(|| unsafe {
+            let ptr = Counting.alloc(layout);
+            let ptr = Counting.realloc(ptr, layout, 8192);
+            Counting.dealloc(ptr, Layout::from_size_align(8192, 8).unwrap());
+        });
+
+        assert!(usage.allocations >= 2);
+        assert!(usage.bytes >= 4096 + 8192);
+        assert!(usage.peak >= 4096 + 8192);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
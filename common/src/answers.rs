@@ -0,0 +1,120 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// Confirmed answers file, at the root of the workspace.
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// Answers to the two parts of a puzzle, as printed by the solvers.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+/// Confirmed answers, keyed by day and then by the hash of the puzzle input.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerFile(BTreeMap<String, BTreeMap<String, Answers>>);
+
+impl AnswerFile {
+    /// Loads the answers file, treating a missing file as empty.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match crate::read_optional(path)? {
+            Some(data) => {
+                toml::from_str(&data).with_context(|| format!("could not parse {}", path.display()))
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let data = toml::to_string(self)?;
+        fs::write(path, data).with_context(|| format!("could not write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, input_hash: &str) -> Option<&Answers> {
+        self.0.get(&day_key(day))?.get(input_hash)
+    }
+
+    /// Fills in any answers that are not confirmed yet, never replacing an
+    /// existing one. Returns whether anything changed.
+    pub fn accept(&mut self, day: u8, input_hash: &str, answers: &Answers) -> bool {
+        let entry = self
+            .0
+            .entry(day_key(day))
+            .or_default()
+            .entry(input_hash.to_string())
+            .or_default();
+
+        let mut changed = false;
+
+        if entry.part1.is_none() && answers.part1.is_some() {
+            entry.part1 = answers.part1.clone();
+            changed = true;
+        }
+
+        if entry.part2.is_none() && answers.part2.is_some() {
+            entry.part2 = answers.part2.clone();
+            changed = true;
+        }
+
+        changed
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(part1: Option<&str>, part2: Option<&str>) -> Answers {
+        Answers {
+            part1: part1.map(str::to_string),
+            part2: part2.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn parse_file() {
+        let data = r#"
+            [day07.0123456789abcdef]
+            part1 = "6440"
+            part2 = "5905"
+
+            [day08.fedcba9876543210]
+            part1 = "2"
+        "#;
+
+        let file = toml::from_str::<AnswerFile>(data).unwrap();
+
+        assert_eq!(
+            file.get(7, "0123456789abcdef"),
+            Some(&answers(Some("6440"), Some("5905")))
+        );
+        assert_eq!(
+            file.get(8, "fedcba9876543210"),
+            Some(&answers(Some("2"), None))
+        );
+        assert_eq!(file.get(7, "fedcba9876543210"), None);
+    }
+
+    #[test]
+    fn accept_keeps_confirmed_answers() {
+        let mut file = AnswerFile::default();
+
+        assert!(file.accept(1, "aa", &answers(Some("142"), None)));
+        assert!(file.accept(1, "aa", &answers(Some("999"), Some("281"))));
+        assert!(!file.accept(1, "aa", &answers(Some("999"), Some("999"))));
+
+        assert_eq!(file.get(1, "aa"), Some(&answers(Some("142"), Some("281"))));
+
+        let res = toml::from_str::<AnswerFile>(&toml::to_string(&file).unwrap()).unwrap();
+        assert_eq!(res, file);
+    }
+}
@@ -0,0 +1,434 @@
+use crate::{
+    alloc::{self, format_bytes, Usage},
+    cli::{BenchArgs, Part},
+    history::{self, Record},
+    input_hash,
+    parse::Mode,
+    Solution,
+};
+use anyhow::{anyhow, Context};
+use humantime::format_duration;
+use std::{
+    fmt::Display,
+    hint::black_box,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    /// Name of the stage in the benchmark history.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part1",
+            Self::Part2 => "part2",
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "Parse"),
+            Self::Part1 => write!(f, "Part 1"),
+            Self::Part2 => write!(f, "Part 2"),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Self::Parse),
+            "part1" => Ok(Self::Part1),
+            "part2" => Ok(Self::Part2),
+            _ => Err(anyhow!("unknown stage '{s}'")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+
+        // Nearest-rank percentile: with 20 or more samples a single slow run
+        // no longer decides the p95.
+        let p95_rank = (len * 95).div_ceil(100);
+        let p95 = sorted[p95_rank.max(1) - 1];
+
+        let mean = sorted.iter().sum::<Duration>() / len as u32;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean,
+            p95,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub iterations: usize,
+    pub stages: Vec<(Stage, Stats)>,
+    /// What each stage allocates in a single run, when allocations are
+    /// counted.
+    pub allocations: Vec<(Stage, Usage)>,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Timings over {} iterations:", self.iterations)?;
+        writeln!(
+            f,
+            "{:<8}{:>18}{:>18}{:>18}{:>18}{:>18}",
+            "Stage", "min", "median", "mean", "p95", "std dev"
+        )?;
+
+        for (stage, stats) in &self.stages {
+            writeln!(
+                f,
+                "{:<8}{:>18}{:>18}{:>18}{:>18}{:>18}",
+                stage.to_string(),
+                format_duration(stats.min).to_string(),
+                format_duration(stats.median).to_string(),
+                format_duration(stats.mean).to_string(),
+                format_duration(stats.p95).to_string(),
+                format_duration(stats.std_dev).to_string(),
+            )?;
+        }
+
+        if self.allocations.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Allocations per run:")?;
+        writeln!(
+            f,
+            "{:<8}{:>18}{:>18}{:>18}",
+            "Stage", "count", "allocated", "peak"
+        )?;
+
+        for (stage, usage) in &self.allocations {
+            writeln!(
+                f,
+                "{:<8}{:>18}{:>18}{:>18}",
+                stage.to_string(),
+                usage.allocations,
+                format_bytes(usage.bytes),
+                format_bytes(usage.peak),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Times each selected stage `iterations` times, after `warmup` untimed runs.
+pub fn bench<S: Solution>(
+    input: &str,
+    part: Part,
+    mode: Mode,
+    iterations: usize,
+    warmup: usize,
+) -> anyhow::Result<Report> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for iteration in 0..warmup + iterations {
+        let timed = iteration >= warmup;
+
+        let now = Instant::now();
+        let (parsed, _) = crate::parse_input::<S>(black_box(input), mode)?;
+        let elapsed = now.elapsed();
+
+        if timed {
+            parse.push(elapsed);
+        }
+
+        if part.part1() {
+            let now = Instant::now();
+            black_box(S::part1(&parsed)?);
+            let elapsed = now.elapsed();
+
+            if timed {
+                part1.push(elapsed);
+            }
+        }
+
+        if part.part2() {
+            let now = Instant::now();
+            black_box(S::part2(&parsed)?);
+            let elapsed = now.elapsed();
+
+            if timed {
+                part2.push(elapsed);
+            }
+        }
+    }
+
+    let stages = [
+        (Stage::Parse, parse),
+        (Stage::Part1, part1),
+        (Stage::Part2, part2),
+    ]
+    .into_iter()
+    .filter_map(|(stage, samples)| Some((stage, Stats::new(&samples)?)))
+    .collect();
+
+    let allocations = match alloc::ENABLED {
+        true => allocations::<S>(input, part, mode)?,
+        false => Vec::new(),
+    };
+
+    Ok(Report {
+        iterations,
+        stages,
+        allocations,
+    })
+}
+
+/// Counts what each selected stage allocates, over one more run.
+fn allocations<S: Solution>(
+    input: &str,
+    part: Part,
+    mode: Mode,
+) -> anyhow::Result<Vec<(Stage, Usage)>> {
+    let (parsed, usage) = alloc::measure(|| crate::parse_input::<S>(black_box(input), mode));
+    let (parsed, _) = parsed?;
+    let mut allocations = vec![(Stage::Parse, usage)];
+
+    if part.part1() {
+        let (res, usage) = alloc::measure(|| S::part1(&parsed).map(black_box));
+        res?;
+        allocations.push((Stage::Part1, usage));
+    }
+
+    if part.part2() {
+        let (res, usage) = alloc::measure(|| S::part2(&parsed).map(black_box));
+        res?;
+        allocations.push((Stage::Part2, usage));
+    }
+
+    Ok(allocations)
+}
+
+/// One stage of a single run: how long it took and what it gave.
+#[derive(Debug)]
+pub struct Timed {
+    pub stage: Stage,
+    pub duration: Duration,
+    /// The answer of a part, or `None` for the parse.
+    pub result: anyhow::Result<Option<String>>,
+}
+
+/// Parses the input once and solves the selected parts, timing each stage.
+/// A part that fails does not stop the other one; a failed parse ends the
+/// run.
+pub fn timed<S: Solution>(input: &str, part: Part, mode: Mode) -> Vec<Timed> {
+    let now = Instant::now();
+    let parsed = crate::parse_input::<S>(input, mode);
+    let duration = now.elapsed();
+
+    let parsed = match parsed {
+        Ok((parsed, _)) => parsed,
+        Err(e) => {
+            return vec![Timed {
+                stage: Stage::Parse,
+                duration,
+                result: Err(e),
+            }]
+        }
+    };
+
+    let mut stages = vec![Timed {
+        stage: Stage::Parse,
+        duration,
+        result: Ok(None),
+    }];
+
+    if part.part1() {
+        stages.push(time_part(Stage::Part1, || S::part1(&parsed)));
+    }
+
+    if part.part2() {
+        stages.push(time_part(Stage::Part2, || S::part2(&parsed)));
+    }
+
+    stages
+}
+
+fn time_part<A: Display>(stage: Stage, solve: impl FnOnce() -> anyhow::Result<A>) -> Timed {
+    let now = Instant::now();
+    let res = solve();
+    let duration = now.elapsed();
+
+    Timed {
+        stage,
+        duration,
+        result: res.map(|answer| Some(answer.to_string())),
+    }
+}
+
+/// Prints the answers for the selected parts followed by their timings,
+/// and records the timings in the benchmark history.
+pub fn run<S: Solution>(
+    input: &str,
+    part: Part,
+    mode: Mode,
+    args: &BenchArgs,
+) -> anyhow::Result<()> {
+    crate::run::<S>(input, part, mode)?;
+
+    let Some(iterations) = args.iterations else {
+        return Ok(());
+    };
+
+    let report = bench::<S>(input, part, mode, iterations, args.warmup)?;
+    println!();
+    print!("{report}");
+
+    if !args.no_history {
+        let records =
+            Record::from_report(S::DAY, &input_hash(input), &history::revision(), &report);
+        history::append(&args.history, &records)?;
+    }
+
+    Ok(())
+}
+
+/// Like [`run`], but without `--bench` the one run is timed, and the time
+/// of each stage is printed after the answers.
+pub fn run_timed<S: Solution>(
+    input: &str,
+    part: Part,
+    mode: Mode,
+    args: &BenchArgs,
+) -> anyhow::Result<()> {
+    if args.iterations.is_some() {
+        return run::<S>(input, part, mode, args);
+    }
+
+    let stages = timed::<S>(input, part, mode);
+    let mut times = Vec::new();
+
+    for Timed {
+        stage,
+        duration,
+        result,
+    } in stages
+    {
+        let answer = result.with_context(|| match stage {
+            Stage::Parse => String::from("could not parse the input"),
+            _ => format!("{} has no answer", stage.to_string().to_lowercase()),
+        })?;
+
+        match (stage, answer) {
+            (Stage::Part1, Some(answer)) => println!("Part 01: {answer}"),
+            (Stage::Part2, Some(answer)) => println!("Part 02: {answer}"),
+            _ => {}
+        }
+
+        times.push((stage, duration));
+    }
+
+    println!();
+
+    for (stage, duration) in &times {
+        let label = match stage {
+            Stage::Parse => String::from("Parsing time:"),
+            _ => format!("{stage} time:"),
+        };
+        println!("{label:<15}{}", format_duration(*duration));
+    }
+
+    let total = times
+        .iter()
+        .map(|(_, duration)| *duration)
+        .sum::<Duration>();
+    println!("Total elapsed: {}", format_duration(total));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn empty_samples() {
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn odd_samples() {
+        let stats = Stats::new(&millis(&[5, 1, 3])).unwrap();
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn even_samples() {
+        let stats = Stats::new(&millis(&[4, 2, 8, 6])).unwrap();
+
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.p95, Duration::from_millis(8));
+        assert_eq!(stats.std_dev.as_micros(), 2236);
+    }
+
+    #[test]
+    fn p95_ignores_single_outlier() {
+        let mut samples = millis(&[1; 19]);
+        samples.push(Duration::from_millis(100));
+        let stats = Stats::new(&samples).unwrap();
+
+        assert_eq!(stats.p95, Duration::from_millis(1));
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{history, parse::Mode};
+use anyhow::Context;
+use clap::{Parser, ValueEnum};
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Directory that `aoc fetch` downloads puzzle inputs into, unless its
+/// config names another.
+pub const CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs");
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Puzzle input file, or `-` to read it from stdin
+    #[arg(conflicts_with = "input_str")]
+    pub input: Option<PathBuf>,
+
+    /// Puzzle input given inline instead of a file
+    #[arg(long)]
+    pub input_str: Option<String>,
+
+    /// Solve the Nth example from the puzzle statement instead
+    #[arg(long, value_name = "N", conflicts_with_all = ["input", "input_str"])]
+    pub example: Option<usize>,
+
+    /// Which part of the puzzle to solve
+    #[arg(long, value_enum, default_value_t = Part::Both)]
+    pub part: Part,
+
+    /// Whether malformed input lines fail the parse or are skipped
+    #[arg(long, value_enum, default_value_t = Mode::Strict)]
+    pub parse_mode: Mode,
+
+    #[command(flatten)]
+    pub bench: BenchArgs,
+}
+
+impl Args {
+    pub fn input(&self) -> Input {
+        match (&self.input, &self.input_str) {
+            (_, Some(input)) => Input::Inline(input.clone()),
+            (Some(path), _) if path.as_os_str() == "-" => Input::Stdin,
+            (Some(path), _) => Input::File(path.clone()),
+            (None, None) => Input::Default,
+        }
+    }
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct BenchArgs {
+    /// Also time the parse and part stages over N runs
+    #[arg(long = "bench", value_name = "N")]
+    pub iterations: Option<usize>,
+
+    /// Untimed runs before benchmarking starts
+    #[arg(long, value_name = "N", default_value_t = 1, requires = "iterations")]
+    pub warmup: usize,
+
+    /// History file that benchmark results are appended to
+    #[arg(long, value_name = "PATH", default_value = history::DEFAULT_PATH, requires = "iterations")]
+    pub history: PathBuf,
+
+    /// Do not record benchmark results in the history file
+    #[arg(long, requires = "iterations")]
+    pub no_history: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Input {
+    Default,
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Input {
+    /// Reads the input, using `default` as the file path for [`Input::Default`].
+    pub fn read(&self, default: &str) -> anyhow::Result<String> {
+        self.read_cached(default, Path::new(CACHE_DIR))
+    }
+
+    /// Like [`Input::read`], but a missing `default` is looked for under the
+    /// same name in `cache_dir`, where fetched inputs are.
+    pub fn read_cached(&self, default: &str, cache_dir: &Path) -> anyhow::Result<String> {
+        match self {
+            Self::Default => {
+                let path = input_path(default, cache_dir);
+
+                fs::read_to_string(&path).with_context(|| match path == Path::new(default) {
+                    true => format!(
+                        "could not read {default}, nor a fetched input in {}",
+                        cache_dir.display()
+                    ),
+                    false => format!("could not read {}", path.display()),
+                })
+            }
+            Self::File(path) => fs::read_to_string(path)
+                .with_context(|| format!("could not read {}", path.display())),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("could not read stdin")?;
+
+                Ok(input)
+            }
+            Self::Inline(input) => Ok(input.clone()),
+        }
+    }
+}
+
+/// The file [`Input::read_cached`] reads for [`Input::Default`]: `default`,
+/// or the file of the same name in `cache_dir` if only that one exists.
+pub fn input_path(default: &str, cache_dir: &Path) -> PathBuf {
+    let path = Path::new(default);
+
+    match path.file_name().map(|name| cache_dir.join(name)) {
+        Some(cached) if !path.exists() && cached.exists() => cached,
+        _ => path.to_path_buf(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl Part {
+    pub fn new(part1: bool, part2: bool) -> Option<Self> {
+        match (part1, part2) {
+            (true, true) => Some(Self::Both),
+            (true, false) => Some(Self::One),
+            (false, true) => Some(Self::Two),
+            (false, false) => None,
+        }
+    }
+
+    pub fn part1(&self) -> bool {
+        matches!(self, Self::One | Self::Both)
+    }
+
+    pub fn part2(&self) -> bool {
+        matches!(self, Self::Two | Self::Both)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_selection() {
+        let args = Args::parse_from(["day"]);
+        assert_eq!(args.input(), Input::Default);
+        assert_eq!(args.part, Part::Both);
+
+        let args = Args::parse_from(["day", "-"]);
+        assert_eq!(args.input(), Input::Stdin);
+
+        let args = Args::parse_from(["day", "example.txt", "--part", "2"]);
+        assert_eq!(args.input(), Input::File(PathBuf::from("example.txt")));
+        assert_eq!(args.part, Part::Two);
+
+        let args = Args::parse_from(["day", "--input-str", "1abc2", "--part", "1"]);
+        assert_eq!(args.input(), Input::Inline(String::from("1abc2")));
+        assert_eq!(args.part, Part::One);
+    }
+
+    #[test]
+    fn bench_options() {
+        let args = Args::parse_from(["day"]);
+        assert_eq!(args.bench.iterations, None);
+
+        let args = Args::parse_from(["day", "--bench", "20", "--warmup", "3"]);
+        assert_eq!(args.bench.iterations, Some(20));
+        assert_eq!(args.bench.warmup, 3);
+        assert_eq!(args.bench.history, PathBuf::from(history::DEFAULT_PATH));
+
+        let args = Args::parse_from(["day", "--bench", "5", "--history", "runs.csv"]);
+        assert_eq!(args.bench.history, PathBuf::from("runs.csv"));
+
+        let res = Args::try_parse_from(["day", "--warmup", "3"]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn parse_mode() {
+        let args = Args::parse_from(["day"]);
+        assert_eq!(args.parse_mode, Mode::Strict);
+
+        let args = Args::parse_from(["day", "--parse-mode", "lenient"]);
+        assert_eq!(args.parse_mode, Mode::Lenient);
+    }
+
+    #[test]
+    fn conflicting_inputs() {
+        let res = Args::try_parse_from(["day", "example.txt", "--input-str", "1abc2"]);
+        assert!(res.is_err());
+
+        let res = Args::try_parse_from(["day", "example.txt", "--example", "1"]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn cached_input() {
+        let dir = std::env::temp_dir().join(format!("common-cache-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day42.txt"), "1abc2\n").unwrap();
+
+        let res = Input::Default.read_cached("/nonexistent/day42.txt", &dir);
+        assert_eq!(res.unwrap(), "1abc2\n");
+
+        let res = Input::Default.read_cached("/nonexistent/day43.txt", &dir);
+        assert!(res
+            .unwrap_err()
+            .to_string()
+            .starts_with("could not read /nonexistent/day43.txt"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn inline_input() {
+        let res = Input::Inline(String::from("1abc2"))
+            .read("missing.txt")
+            .unwrap();
+        assert_eq!(res, "1abc2");
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{cli::Part, Solution};
+use anyhow::anyhow;
+
+/// An example from a puzzle statement, with the answers the statement gives
+/// for it. Examples that only illustrate one part leave the other unset.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Example {
+    pub path: &'static str,
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    /// The parts of `part` this example has an answer for.
+    pub fn part(&self, part: Part) -> Option<Part> {
+        Part::new(
+            part.part1() && self.part1.is_some(),
+            part.part2() && self.part2.is_some(),
+        )
+    }
+}
+
+/// Declares an example stored in the day's `examples` directory.
+#[macro_export]
+macro_rules! example {
+    ($file:literal, $part1:expr, $part2:expr $(,)?) => {
+        $crate::example::Example {
+            path: concat!(env!("CARGO_MANIFEST_DIR"), "/examples/", $file),
+            input: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/", $file)),
+            part1: $part1,
+            part2: $part2,
+        }
+    };
+}
+
+/// Looks up an example by its 1-based number, along with the parts of
+/// `part` that it can be checked against.
+pub fn select<S: Solution>(number: usize, part: Part) -> anyhow::Result<(&'static Example, Part)> {
+    let example = number
+        .checked_sub(1)
+        .and_then(|index| S::EXAMPLES.get(index))
+        .ok_or_else(|| {
+            anyhow!(
+                "day {} has {} example(s), there is no example {number}",
+                S::DAY,
+                S::EXAMPLES.len()
+            )
+        })?;
+
+    let part = example
+        .part(part)
+        .ok_or_else(|| anyhow!("example {number} has no answer for the selected part"))?;
+
+    Ok((example, part))
+}
+
+/// Asserts that every example of the day solves to the answers in its
+/// puzzle statement.
+pub fn check<S: Solution>() {
+    assert!(!S::EXAMPLES.is_empty(), "day {} has no examples", S::DAY);
+
+    for (index, example) in S::EXAMPLES.iter().enumerate() {
+        let number = index + 1;
+        let parsed = S::parse(example.input)
+            .unwrap_or_else(|e| panic!("example {number} failed to parse: {e:?}"));
+
+        if let Some(expected) = example.part1 {
+            let res = S::part1(&parsed)
+                .unwrap_or_else(|e| panic!("example {number}, part 1 failed: {e:?}"))
+                .to_string();
+            assert_eq!(res, expected, "example {number}, part 1");
+        }
+
+        if let Some(expected) = example.part2 {
+            let res = S::part2(&parsed)
+                .unwrap_or_else(|e| panic!("example {number}, part 2 failed: {e:?}"))
+                .to_string();
+            assert_eq!(res, expected, "example {number}, part 2");
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{cli::Part, parse::Mode, Solution};
+use std::{fs, io::ErrorKind, panic};
+
+/// Feeds arbitrary bytes through parsing, in both modes, and the selected
+/// parts. Errors are the expected outcome for most inputs; only a panic is a
+/// bug.
+pub fn run<S: Solution>(data: &[u8], part: Part) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    for mode in [Mode::Strict, Mode::Lenient] {
+        if let Ok((parsed, _)) = crate::parse_input::<S>(input, mode) {
+            if part.part1() {
+                let _ = S::part1(&parsed);
+            }
+
+            if part.part2() {
+                let _ = S::part2(&parsed);
+            }
+        }
+    }
+}
+
+/// Replays every input the fuzzer found a panic with for this day, from
+/// `fuzz/regressions/dayNN` at the root of the workspace.
+pub fn regressions<S: Solution>() {
+    let dir = format!(
+        "{}/../fuzz/regressions/day{:02}",
+        env!("CARGO_MANIFEST_DIR"),
+        S::DAY
+    );
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return,
+        Err(e) => panic!("could not read {dir}: {e}"),
+    };
+
+    for entry in entries {
+        let path = entry.unwrap().path();
+        let data = fs::read(&path).unwrap();
+
+        let res = panic::catch_unwind(|| run::<S>(&data, Part::Both));
+        assert!(res.is_ok(), "{} panicked", path.display());
+    }
+}
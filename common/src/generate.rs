@@ -0,0 +1,124 @@
+use crate::Solution;
+use std::{fmt::Debug, ops::RangeInclusive};
+
+/// A small seeded random number generator (SplitMix64). Its output only
+/// depends on the seed, so generated inputs can be reproduced on any machine
+/// and with any version of the dependencies.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in the inclusive `range`, which must not be empty.
+    pub fn range<T>(&mut self, range: RangeInclusive<T>) -> T
+    where
+        T: TryInto<i128> + TryFrom<i128>,
+        <T as TryInto<i128>>::Error: Debug,
+        <T as TryFrom<i128>>::Error: Debug,
+    {
+        let (start, end) = range.into_inner();
+        let start: i128 = start.try_into().unwrap();
+        let end: i128 = end.try_into().unwrap();
+        assert!(start <= end, "empty range {start}..={end}");
+
+        let span = (end - start) as u128 + 1;
+        let offset = (self.next_u64() as u128 * span) >> 64;
+
+        T::try_from(start + offset as i128).unwrap()
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.range(0..=index));
+        }
+    }
+}
+
+/// Generates an input for the day from `seed`.
+pub fn input<S: Solution>(size: usize, seed: u64) -> String {
+    S::generate(size, &mut Rng::new(seed))
+}
+
+/// Asserts that generated inputs of a few sizes are reproducible and solve
+/// without errors.
+pub fn check<S: Solution>() {
+    for size in [1, 5, 20] {
+        for seed in 0..5 {
+            let generated = input::<S>(size, seed);
+            assert_eq!(
+                generated,
+                input::<S>(size, seed),
+                "size {size}, seed {seed}"
+            );
+
+            let parsed = S::parse(&generated)
+                .unwrap_or_else(|e| panic!("size {size}, seed {seed} failed to parse: {e:?}"));
+            S::part1(&parsed)
+                .unwrap_or_else(|e| panic!("size {size}, seed {seed}, part 1 failed: {e:?}"));
+            S::part2(&parsed)
+                .unwrap_or_else(|e| panic!("size {size}, seed {seed}, part 2 failed: {e:?}"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible() {
+        let values = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(values(7), values(7));
+        assert_ne!(values(7), values(8));
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(0);
+
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3i64..=3)));
+            assert_eq!(rng.range(5usize..=5), 5);
+        }
+
+        rng.range(0..=u64::MAX);
+        rng.range(i64::MIN..=i64::MAX);
+        assert!((0..=9).all(|n| (0..100).any(|_| rng.range(0..=9) == n)));
+    }
+
+    #[test]
+    fn shuffles() {
+        let mut rng = Rng::new(0);
+        let mut items = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+
+        assert_ne!(items, (0..10).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,345 @@
+use crate::bench::{Report, Stage, Stats};
+use anyhow::{bail, Context};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// History file shared by every day, at the root of the workspace.
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../bench-history.csv");
+
+const HEADER: &str =
+    "timestamp,day,stage,revision,input_hash,iterations,min_ns,median_ns,mean_ns,p95_ns,std_dev_ns";
+
+/// One benchmarked stage, as stored in the history file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub timestamp: u64,
+    pub day: u8,
+    pub stage: Stage,
+    pub revision: String,
+    pub input_hash: String,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+impl Record {
+    pub fn from_report(day: u8, input_hash: &str, revision: &str, report: &Report) -> Vec<Self> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        report
+            .stages
+            .iter()
+            .map(|(stage, stats)| Self {
+                timestamp,
+                day,
+                stage: *stage,
+                revision: revision.to_string(),
+                input_hash: input_hash.to_string(),
+                iterations: report.iterations,
+                stats: *stats,
+            })
+            .collect()
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Stats {
+            min,
+            median,
+            mean,
+            p95,
+            std_dev,
+        } = self.stats;
+
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.day,
+            self.stage.key(),
+            self.revision,
+            self.input_hash,
+            self.iterations,
+            min.as_nanos(),
+            median.as_nanos(),
+            mean.as_nanos(),
+            p95.as_nanos(),
+            std_dev.as_nanos(),
+        )
+    }
+}
+
+impl FromStr for Record {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = s.split(',').collect::<Vec<_>>();
+
+        let [timestamp, day, stage, revision, input_hash, iterations, min, median, mean, p95, std_dev] =
+            fields[..]
+        else {
+            bail!("expected 11 fields, found {}", fields.len());
+        };
+
+        let nanos =
+            |value: &str| -> anyhow::Result<Duration> { Ok(Duration::from_nanos(value.parse()?)) };
+
+        Ok(Self {
+            timestamp: timestamp.parse()?,
+            day: day.parse()?,
+            stage: stage.parse()?,
+            revision: revision.to_string(),
+            input_hash: input_hash.to_string(),
+            iterations: iterations.parse()?,
+            stats: Stats {
+                min: nanos(min)?,
+                median: nanos(median)?,
+                mean: nanos(mean)?,
+                p95: nanos(p95)?,
+                std_dev: nanos(std_dev)?,
+            },
+        })
+    }
+}
+
+/// Appends the records to the history file, creating it if needed.
+pub fn append(path: &Path, records: &[Record]) -> anyhow::Result<()> {
+    let new_file = fs::metadata(path).map(|m| m.len() == 0).unwrap_or(true);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("could not open {}", path.display()))?;
+
+    if new_file {
+        writeln!(file, "{HEADER}")?;
+    }
+
+    for record in records {
+        writeln!(file, "{record}")?;
+    }
+
+    Ok(())
+}
+
+/// Reads every record in the history file, oldest first.
+pub fn load(path: &Path) -> anyhow::Result<Vec<Record>> {
+    let data =
+        fs::read_to_string(path).with_context(|| format!("could not read {}", path.display()))?;
+
+    data.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && *line != HEADER)
+        .map(|(index, line)| {
+            line.parse()
+                .with_context(|| format!("{}:{}: invalid record", path.display(), index + 1))
+        })
+        .collect()
+}
+
+/// Short git revision of the working tree, marked `-dirty` when it has
+/// uncommitted changes.
+pub fn revision() -> String {
+    let git = |args: &[&str]| -> Option<String> {
+        let output = Command::new("git").args(args).output().ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(rev) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{rev}-dirty")
+        }
+        Some(rev) => rev,
+        None => String::from("unknown"),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    New,
+    Ok,
+    Regression,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::New => write!(f, "new"),
+            Self::Ok => write!(f, "ok"),
+            Self::Regression => write!(f, "REGRESSION"),
+        }
+    }
+}
+
+/// The latest run of a stage measured against its earlier runs on the same input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: Stage,
+    pub input_hash: String,
+    pub revision: String,
+    pub baseline: Option<Duration>,
+    pub latest: Duration,
+    pub status: Status,
+}
+
+impl Comparison {
+    /// Relative change of the latest median against the baseline, in percent.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?.as_secs_f64();
+        Some((self.latest.as_secs_f64() - baseline) / baseline * 100.0)
+    }
+}
+
+/// Compares the latest median of every day, stage and input against the
+/// median of its earlier medians, flagging anything slower by more than
+/// `threshold` percent.
+pub fn compare(records: &[Record], threshold: f64) -> Vec<Comparison> {
+    let mut groups: HashMap<(u8, Stage, &str), Vec<&Record>> = HashMap::new();
+
+    for record in records {
+        let key = (record.day, record.stage, record.input_hash.as_str());
+        groups.entry(key).or_default().push(record);
+    }
+
+    let mut comparisons = groups
+        .into_values()
+        .filter_map(|runs| {
+            let (latest, earlier) = runs.split_last()?;
+            let baseline = median(earlier.iter().map(|r| r.stats.median).collect());
+
+            let status = match baseline {
+                None => Status::New,
+                Some(baseline)
+                    if latest.stats.median > baseline.mul_f64(1.0 + threshold / 100.0) =>
+                {
+                    Status::Regression
+                }
+                Some(_) => Status::Ok,
+            };
+
+            Some(Comparison {
+                day: latest.day,
+                stage: latest.stage,
+                input_hash: latest.input_hash.clone(),
+                revision: latest.revision.clone(),
+                baseline,
+                latest: latest.stats.median,
+                status,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    comparisons
+        .sort_by(|a, b| (a.day, a.stage, &a.input_hash).cmp(&(b.day, b.stage, &b.input_hash)));
+    comparisons
+}
+
+fn median(mut values: Vec<Duration>) -> Option<Duration> {
+    values.sort();
+
+    let len = values.len();
+    match len {
+        0 => None,
+        _ if len.is_multiple_of(2) => Some((values[len / 2 - 1] + values[len / 2]) / 2),
+        _ => Some(values[len / 2]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, stage: Stage, input_hash: &str, median_ms: u64) -> Record {
+        let median = Duration::from_millis(median_ms);
+
+        Record {
+            timestamp: 1_700_000_000,
+            day,
+            stage,
+            revision: String::from("abc1234"),
+            input_hash: input_hash.to_string(),
+            iterations: 10,
+            stats: Stats {
+                min: median,
+                median,
+                mean: median,
+                p95: median,
+                std_dev: Duration::ZERO,
+            },
+        }
+    }
+
+    #[test]
+    fn record_round_trip() {
+        let original = record(5, Stage::Part2, "cbf29ce484222325", 12);
+        let res = original.to_string().parse::<Record>().unwrap();
+        assert_eq!(res, original);
+
+        assert!("1,2,3".parse::<Record>().is_err());
+        assert!(HEADER.parse::<Record>().is_err());
+    }
+
+    #[test]
+    fn append_and_load() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.csv", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let first = vec![record(1, Stage::Parse, "aa", 1)];
+        let second = vec![
+            record(1, Stage::Part1, "aa", 2),
+            record(1, Stage::Part2, "aa", 3),
+        ];
+        append(&path, &first).unwrap();
+        append(&path, &second).unwrap();
+
+        let res = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(res, [first, second].concat());
+    }
+
+    #[test]
+    fn flags_regressions_against_baseline() {
+        let records = vec![
+            record(10, Stage::Part2, "aa", 100),
+            record(10, Stage::Part2, "aa", 110),
+            record(10, Stage::Part2, "aa", 90),
+            record(10, Stage::Part2, "aa", 120),
+            record(5, Stage::Part2, "aa", 100),
+            record(5, Stage::Part2, "aa", 105),
+            record(5, Stage::Part2, "bb", 900),
+        ];
+
+        let res = compare(&records, 10.0);
+        let statuses = res
+            .iter()
+            .map(|c| (c.day, c.input_hash.as_str(), c.status))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            statuses,
+            vec![
+                (5, "aa", Status::Ok),
+                (5, "bb", Status::New),
+                (10, "aa", Status::Regression),
+            ]
+        );
+        assert_eq!(res[2].baseline, Some(Duration::from_millis(100)));
+        assert_eq!(res[2].change().map(f64::round), Some(20.0));
+    }
+}
@@ -0,0 +1,188 @@
+use answers::Answers;
+use anyhow::Context;
+use clap::Parser;
+use cli::{Args, Part};
+use example::Example;
+use generate::Rng;
+use parse::{Mode, ParseError};
+use std::{fmt::Display, fs, io::ErrorKind, path::Path};
+
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod example;
+pub mod fuzz;
+pub mod generate;
+pub mod history;
+pub mod parse;
+
+/// A single day's puzzle, split into a shared parse stage and the two parts
+/// that are solved from the parsed data.
+pub trait Solution {
+    /// Puzzle day, as in the Advent of Code calendar.
+    const DAY: u8;
+
+    /// Path of the puzzle input used when no other input is given.
+    const INPUT: &'static str;
+
+    /// Examples from the puzzle statement, see [`example!`].
+    const EXAMPLES: &'static [Example];
+
+    type Parsed;
+    type Answer: Display;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed>;
+
+    /// Like [`Solution::parse`], but skips malformed lines instead of failing
+    /// and returns them alongside the parsed data. Days that do not read
+    /// their input line by line have nothing to skip and parse strictly.
+    fn parse_lenient(input: &str) -> anyhow::Result<(Self::Parsed, Vec<ParseError>)> {
+        Ok((Self::parse(input)?, Vec::new()))
+    }
+
+    /// Solves part 1. Fails when the input parses but has no answer, such as
+    /// a route that never arrives or an answer that overflows.
+    fn part1(parsed: &Self::Parsed) -> anyhow::Result<Self::Answer>;
+
+    /// Solves part 2, failing like [`Solution::part1`].
+    fn part2(parsed: &Self::Parsed) -> anyhow::Result<Self::Answer>;
+
+    /// Generates a valid puzzle input that both parts can be solved for.
+    /// `size` scales it, roughly as the number of lines or the side of a
+    /// grid.
+    fn generate(size: usize, rng: &mut Rng) -> String;
+}
+
+/// Entry point for a single day's binary, driven by its command line.
+pub fn main<S: Solution>() -> anyhow::Result<()> {
+    main_with::<S>(bench::run::<S>)
+}
+
+/// Like [`main`], but a run without `--bench` also prints how long each
+/// stage took, as day 11 always has.
+pub fn main_timed<S: Solution>() -> anyhow::Result<()> {
+    main_with::<S>(bench::run_timed::<S>)
+}
+
+fn main_with<S: Solution>(
+    run: fn(&str, Part, Mode, &cli::BenchArgs) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    match args.example {
+        Some(number) => {
+            let (example, part) = example::select::<S>(number, args.part)?;
+            run(example.input, part, args.parse_mode, &args.bench)
+        }
+        None => {
+            let input = args.input().read(S::INPUT)?;
+            run(&input, args.part, args.parse_mode, &args.bench)
+        }
+    }
+}
+
+/// Parses the input in the given mode, along with the lines a lenient parse
+/// skipped.
+pub fn parse_input<S: Solution>(
+    input: &str,
+    mode: Mode,
+) -> anyhow::Result<(S::Parsed, Vec<ParseError>)> {
+    match mode {
+        Mode::Strict => Ok((S::parse(input)?, Vec::new())),
+        Mode::Lenient => S::parse_lenient(input),
+    }
+}
+
+/// Parses the input once, strictly, and solves the selected parts.
+pub fn solve<S: Solution>(input: &str, part: Part) -> anyhow::Result<Answers> {
+    let parsed = S::parse(input)?;
+    answers::<S>(&parsed, part)
+}
+
+fn answers<S: Solution>(parsed: &S::Parsed, part: Part) -> anyhow::Result<Answers> {
+    let part1 = match part.part1() {
+        true => Some(
+            S::part1(parsed)
+                .context("part 1 has no answer")?
+                .to_string(),
+        ),
+        false => None,
+    };
+
+    let part2 = match part.part2() {
+        true => Some(
+            S::part2(parsed)
+                .context("part 2 has no answer")?
+                .to_string(),
+        ),
+        false => None,
+    };
+
+    Ok(Answers { part1, part2 })
+}
+
+/// Parses the input once and prints the selected parts, reporting any lines
+/// that a lenient parse skipped.
+pub fn run<S: Solution>(input: &str, part: Part, mode: Mode) -> anyhow::Result<()> {
+    let (parsed, skipped) = parse_input::<S>(input, mode)?;
+
+    if !skipped.is_empty() {
+        eprintln!("{}", parse::summary(&skipped));
+    }
+
+    let answers = answers::<S>(&parsed, part)?;
+
+    if let Some(res) = answers.part1 {
+        println!("Part 01: {res}");
+    }
+
+    if let Some(res) = answers.part2 {
+        println!("Part 02: {res}");
+    }
+
+    Ok(())
+}
+
+/// Stable FNV-1a hash of the puzzle input, so results can be tied to the
+/// input they were measured on.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    format!("{hash:016x}")
+}
+
+/// Reads a file that may not exist yet, such as one the tool creates on first
+/// save. A missing file is `None`; any other failure is an error.
+pub fn read_optional(path: &Path) -> anyhow::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(data) => Ok(Some(data)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("could not read {}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn reads_optional_files() {
+        let dir = env::temp_dir().join(format!("aoc-read-optional-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("present.toml");
+        fs::write(&path, "data").unwrap();
+        assert_eq!(read_optional(&path).unwrap().as_deref(), Some("data"));
+
+        assert_eq!(read_optional(&dir.join("missing.toml")).unwrap(), None);
+
+        let err = read_optional(&dir).unwrap_err();
+        assert!(err.to_string().starts_with("could not read"), "{err}");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
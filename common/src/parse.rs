@@ -0,0 +1,310 @@
+use clap::ValueEnum;
+use nom::{
+    character::complete::multispace0,
+    combinator::eof,
+    error::{context, VerboseError, VerboseErrorKind},
+    sequence::{terminated, tuple},
+    Finish,
+};
+use std::fmt::Display;
+
+/// How to deal with malformed lines in line-oriented inputs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Mode {
+    /// Fail on the first malformed line.
+    #[default]
+    Strict,
+    /// Skip malformed lines and report them afterwards.
+    Lenient,
+}
+
+/// nom's result type, with errors that keep the context they failed in.
+pub type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+/// A parse failure, located in the input and described by what the parser
+/// expected to find there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, in characters.
+    pub column: usize,
+    /// The full text of the offending line.
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Builds an error at a byte `offset` into `input`.
+    pub fn at(input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: input[line_start..].lines().next().unwrap_or("").to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Converts a nom error from parsing `input`. The innermost context is
+    /// used as the expected construct, falling back to nom's own error.
+    pub fn from_nom(input: &str, error: VerboseError<&str>) -> Self {
+        let context = error
+            .errors
+            .iter()
+            .find_map(|(remaining, kind)| match kind {
+                VerboseErrorKind::Context(context) => Some((*remaining, context.to_string())),
+                _ => None,
+            });
+
+        let innermost = error
+            .errors
+            .first()
+            .map(|(remaining, kind)| (*remaining, describe(kind)));
+
+        let (remaining, expected) = context
+            .or(innermost)
+            .unwrap_or((input, String::from("valid input")));
+
+        Self::at(input, input.len() - remaining.len(), expected)
+    }
+
+    /// Moves an error found while parsing a single line to that line's number.
+    pub fn on_line(self, line: usize) -> Self {
+        Self { line, ..self }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "  {}", self.text)?;
+        write!(f, "  {:>width$}", "^", width = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn describe(kind: &VerboseErrorKind) -> String {
+    match kind {
+        VerboseErrorKind::Context(context) => context.to_string(),
+        VerboseErrorKind::Char(c) => format!("'{c}'"),
+        VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+    }
+}
+
+/// An unrecoverable error at `input`, for values that parse but cannot be
+/// used, such as numbers that would overflow.
+pub fn failure<'a>(input: &'a str, expected: &'static str) -> nom::Err<VerboseError<&'a str>> {
+    nom::Err::Failure(VerboseError {
+        errors: vec![(input, VerboseErrorKind::Context(expected))],
+    })
+}
+
+/// Runs `parser` over the whole of `input`, which may only be followed by
+/// whitespace.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, ParseError> {
+    parse_to_end(input, parser, "end of input")
+}
+
+fn parse_to_end<'a, T>(
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+    end: &'static str,
+) -> Result<T, ParseError> {
+    let end = tuple((multispace0, context(end, eof)));
+
+    terminated(parser, end)(input)
+        .finish()
+        .map(|(_, value)| value)
+        .map_err(|e| ParseError::from_nom(input, e))
+}
+
+/// Parses every line of `input` with `parser`, stopping at the first line
+/// that fails. Blank lines at the end of the input are ignored.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(index, line, &mut parser))
+        .collect()
+}
+
+/// Parses every line of `input` with `parser`, skipping the lines that fail.
+/// Returns the parsed lines along with an error for every skipped line.
+pub fn parse_lines_lenient<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> (Vec<T>, Vec<ParseError>) {
+    let mut values = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (index, line) in input.trim_end().lines().enumerate() {
+        match parse_line(index, line, &mut parser) {
+            Ok(value) => values.push(value),
+            Err(e) => skipped.push(e),
+        }
+    }
+
+    (values, skipped)
+}
+
+fn parse_line<'a, T>(
+    index: usize,
+    line: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, ParseError> {
+    parse_to_end(line, parser, "end of line").map_err(|e| e.on_line(index + 1))
+}
+
+/// Describes the lines skipped by a lenient parse, grouped by what was
+/// expected in them.
+pub fn summary(skipped: &[ParseError]) -> String {
+    const SHOWN_LINES: usize = 5;
+
+    let mut groups: Vec<(&str, Vec<usize>)> = Vec::new();
+
+    for error in skipped {
+        match groups
+            .iter_mut()
+            .find(|(expected, _)| *expected == error.expected)
+        {
+            Some((_, lines)) => lines.push(error.line),
+            None => groups.push((&error.expected, vec![error.line])),
+        }
+    }
+
+    let mut res = format!("skipped {} malformed line(s):", skipped.len());
+
+    for (expected, lines) in groups {
+        let shown = lines
+            .iter()
+            .take(SHOWN_LINES)
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let more = match lines.len().saturating_sub(SHOWN_LINES) {
+            0 => String::new(),
+            n => format!(" and {n} more"),
+        };
+
+        let noun = if lines.len() == 1 { "line" } else { "lines" };
+
+        res.push_str(&format!(
+            "\n  {} expected {expected} ({noun} {shown}{more})",
+            lines.len()
+        ));
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{bytes::complete::tag, character::complete::u64, sequence::preceded};
+
+    fn value(input: &str) -> IResult<&str, u64> {
+        preceded(tag("value "), context("a number", u64))(input)
+    }
+
+    #[test]
+    fn locates_errors() {
+        let res = ParseError::at("abc\ndef\nghi", 5, "x");
+        assert_eq!(res.line, 2);
+        assert_eq!(res.column, 2);
+        assert_eq!(res.text, "def");
+
+        let res = ParseError::at("é\nü", 3, "x");
+        assert_eq!(res.line, 2);
+        assert_eq!(res.column, 1);
+        assert_eq!(res.text, "ü");
+    }
+
+    #[test]
+    fn uses_innermost_context() {
+        let res = parse_all("value x", value).unwrap_err();
+        assert_eq!(res.column, 7);
+        assert_eq!(res.expected, "a number");
+        assert_eq!(
+            res.to_string(),
+            "line 1, column 7: expected a number\n  value x\n        ^"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_nom_error() {
+        let res = parse_all("valeu 1", value).unwrap_err();
+        assert_eq!(res.column, 1);
+        assert_eq!(res.expected, "tag");
+    }
+
+    #[test]
+    fn rejects_trailing_input() {
+        assert_eq!(parse_all("value 1\n\n", value), Ok(1));
+
+        let res = parse_all("value 12 3", value).unwrap_err();
+        assert_eq!(res.column, 10);
+        assert_eq!(res.expected, "end of input");
+    }
+
+    #[test]
+    fn reports_line_numbers() {
+        assert_eq!(parse_lines("value 1\nvalue 2\n\n", value), Ok(vec![1, 2]));
+
+        let res = parse_lines("value 1\nvalue 2\nvalue", value).unwrap_err();
+        assert_eq!(res.line, 3);
+        assert_eq!(res.text, "value");
+
+        let res = parse_lines("value 1 2", value).unwrap_err();
+        assert_eq!(res.expected, "end of line");
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let (values, skipped) = parse_lines_lenient("value 1\nvalue\nvalue 3 4\nvalue 5", value);
+        assert_eq!(values, vec![1, 5]);
+        assert_eq!(
+            skipped.iter().map(|e| e.line).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+    }
+
+    #[test]
+    fn summarises_skipped_lines() {
+        let input = (1..=9)
+            .map(|n| if n % 2 == 0 { "value x" } else { "value 1 x" })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (_, skipped) = parse_lines_lenient(&input, value);
+        assert_eq!(
+            summary(&skipped),
+            "skipped 9 malformed line(s):\n  \
+             5 expected end of line (lines 1, 3, 5, 7, 9)\n  \
+             4 expected a number (lines 2, 4, 6, 8)"
+        );
+
+        let input = ["value x"; 7].join("\n");
+        let (_, skipped) = parse_lines_lenient(&input, value);
+        assert_eq!(
+            summary(&skipped),
+            "skipped 7 malformed line(s):\n  7 expected a number (lines 1, 2, 3, 4, 5 and 2 more)"
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use common::bench::Stats;
+use day01::{parser_nom, Day01, Matcher, Vocabulary};
+use humantime::format_duration;
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const RUNS: usize = 20;
+
+type Scanner = fn(&str) -> Vec<u32>;
+
+/// How part 1 read a line before the automaton.
+fn digits(line: &str) -> Vec<u32> {
+    line.chars().filter_map(|c| c.to_digit(10)).collect()
+}
+
+fn time(mut f: impl FnMut() -> usize) -> Stats {
+    let samples = (0..RUNS)
+        .map(|_| {
+            let now = Instant::now();
+            black_box(f());
+            now.elapsed()
+        })
+        .collect::<Vec<Duration>>();
+
+    Stats::new(&samples).unwrap()
+}
+
+fn main() {
+    println!(
+        "{:<10}{:<9}{:>18}{:>18}{:>10}",
+        "Lines", "Words", "baseline", "automaton", "speedup"
+    );
+
+    for size in [1_000, 10_000, 100_000] {
+        let input = common::generate::input::<Day01>(size, 0);
+        let lines = input.lines().collect::<Vec<_>>();
+
+        // Each vocabulary against the scan the solution used for it before.
+        let baselines: [(&str, Scanner); 2] = [("digits", digits), ("english", parser_nom)];
+
+        for (name, baseline) in baselines {
+            let vocabulary = Vocabulary::named(name).unwrap();
+
+            let before = time(|| lines.iter().map(|line| baseline(line).len()).sum());
+
+            let automaton = time(|| {
+                let matcher = Matcher::new(&vocabulary);
+                lines.iter().map(|line| matcher.digits(line).len()).sum()
+            });
+
+            println!(
+                "{:<10}{:<9}{:>18}{:>18}{:>9.1}x",
+                size,
+                name,
+                format_duration(before.median).to_string(),
+                format_duration(automaton.median).to_string(),
+                before.median.as_secs_f64() / automaton.median.as_secs_f64()
+            );
+        }
+    }
+}
@@ -0,0 +1,658 @@
+//! Day 1: Trebuchet?! Every line of the calibration document hides a value
+//! made of its first and last digit.
+
+use common::{example, example::Example, generate::Rng, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::anychar,
+    combinator::{map, value, verify},
+    Finish, IResult,
+};
+use serde::Serialize;
+use std::{
+    cmp::{max, Reverse},
+    collections::VecDeque,
+    ops::Range,
+};
+
+mod unicode;
+mod vocabulary;
+
+pub use unicode::decimal_value;
+pub use vocabulary::{Vocabulary, NAMES};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day01.txt");
+    const EXAMPLES: &'static [Example] = &[
+        example!("1.txt", Some("142"), None),
+        example!("2.txt", None, Some("281")),
+    ];
+
+    type Parsed = Vec<String>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(lines: &Self::Parsed) -> anyhow::Result<u32> {
+        let digits = Matcher::new(&Vocabulary::digits());
+
+        Ok(lines
+            .iter()
+            .map(|line| digits.digits(line))
+            .filter_map(|numbers| calibration_value(&numbers))
+            .sum())
+    }
+
+    fn part2(lines: &Self::Parsed) -> anyhow::Result<u32> {
+        let words = Matcher::new(&Vocabulary::english());
+
+        Ok(lines
+            .iter()
+            .map(|line| words.digits(line))
+            .filter_map(|numbers| calibration_value(&numbers))
+            .sum())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+/// The calibration value made of the first and last of the digits found on a
+/// line, or `None` for a line without digits.
+pub fn calibration_value(numbers: &[u32]) -> Option<u32> {
+    let first = numbers.first()?;
+    let last = numbers.last()?;
+
+    Some((first * 10) + last)
+}
+
+/// How a digit was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Kind {
+    Digit,
+    Word,
+}
+
+/// A digit found on a line.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Token {
+    /// Byte offsets of the digit in its line.
+    pub span: Range<usize>,
+    pub text: String,
+    pub kind: Kind,
+    pub value: u32,
+}
+
+/// How one line of a calibration document was read.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LineReport {
+    /// Starting from 1.
+    pub number: usize,
+    pub text: String,
+    pub tokens: Vec<Token>,
+    pub first: Option<u32>,
+    pub last: Option<u32>,
+    pub calibration: Option<u32>,
+    /// Set for lines without digits, which add nothing to the sum.
+    pub no_digits: bool,
+}
+
+/// Every line of a calibration document with the digits found on it, for
+/// finding the lines that were misread.
+pub fn report(input: &str, vocabulary: &Vocabulary) -> Vec<LineReport> {
+    let matcher = Matcher::new(vocabulary);
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let tokens = matcher.tokens(line);
+            let first = tokens.first().map(|token| token.value);
+            let last = tokens.last().map(|token| token.value);
+
+            LineReport {
+                number: index + 1,
+                text: line.to_string(),
+                first,
+                last,
+                calibration: first.zip(last).map(|(first, last)| first * 10 + last),
+                no_digits: tokens.is_empty(),
+                tokens,
+            }
+        })
+        .collect()
+}
+
+/// Every digit on a line, in order, whether written as a digit character or
+/// as one of the words of `vocabulary`. Spelled digits may share letters if
+/// the vocabulary is overlapping, so `"twone"` gives `[2, 1]` in English.
+/// Builds a [`Matcher`] for the one line, so scanning many lines is better
+/// done with a matcher of their own.
+pub fn parser(input: &str, vocabulary: &Vocabulary) -> Vec<u32> {
+    Matcher::new(vocabulary).digits(input)
+}
+
+/// The English scanner [`Matcher`] replaced, trying every word at every
+/// offset. Kept as the baseline of the benchmark.
+pub fn parser_nom(input: &str) -> Vec<u32> {
+    let mut input = input;
+    let mut output = Vec::new();
+
+    while !input.is_empty() {
+        let res = valid_value(input).finish();
+
+        if let Ok((remaining_input, value)) = res {
+            output.push(value);
+
+            let start = max(1, input.len() - remaining_input.len() - 1);
+            input = &input[start..];
+        } else {
+            let mut chars = input.chars();
+            chars.next();
+            input = chars.as_str();
+        }
+    }
+
+    output
+}
+
+/// An Aho–Corasick automaton over the digit characters and the words of a
+/// vocabulary, which finds every one of them on a line in a single pass,
+/// overlapping or not.
+#[derive(Debug, Clone)]
+pub struct Matcher {
+    /// The class of each byte: bytes that appear in no pattern share class
+    /// 0, which keeps the transition table small.
+    classes: [u8; 256],
+    width: usize,
+    /// The next state for each state and byte class, with every failure
+    /// transition already followed.
+    next: Vec<u32>,
+    /// The patterns, as their length, value and kind, that end in each
+    /// state, as a range of `matches`.
+    ends: Vec<Range<u32>>,
+    matches: Vec<(usize, u32, Kind)>,
+    overlapping: bool,
+}
+
+impl Matcher {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let case_insensitive = vocabulary.is_case_insensitive();
+        let mut patterns = match vocabulary.has_unicode_digits() {
+            true => unicode::decimal_digits()
+                .map(|(digit, value)| (digit.to_string(), value, Kind::Digit))
+                .collect::<Vec<_>>(),
+            false => (0..10)
+                .map(|digit| (digit.to_string(), digit, Kind::Digit))
+                .collect(),
+        };
+
+        // ASCII letters share a class with their other case below, which
+        // leaves other letters to be spelled in both cases here.
+        for (word, value) in vocabulary.words() {
+            patterns.push((word.to_string(), value, Kind::Word));
+
+            if case_insensitive {
+                patterns.push((word.to_lowercase(), value, Kind::Word));
+                patterns.push((word.to_uppercase(), value, Kind::Word));
+            }
+        }
+
+        let mut classes = [0; 256];
+        let mut width = 1;
+
+        for &byte in patterns
+            .iter()
+            .flat_map(|(pattern, _, _)| pattern.as_bytes())
+        {
+            if classes[byte as usize] == 0 {
+                classes[byte as usize] = width as u8;
+
+                if case_insensitive && byte.is_ascii_alphabetic() {
+                    classes[byte.to_ascii_lowercase() as usize] = width as u8;
+                    classes[byte.to_ascii_uppercase() as usize] = width as u8;
+                }
+
+                width += 1;
+            }
+        }
+
+        // The trie of the patterns, with the one pattern, if any, that ends
+        // in each state. The first spelling of a pattern wins.
+        let mut next = vec![u32::MAX; width];
+        let mut output = vec![None];
+
+        for (pattern, value, kind) in &patterns {
+            let mut state = 0;
+
+            for &byte in pattern.as_bytes() {
+                let index = state * width + classes[byte as usize] as usize;
+
+                state = match next[index] {
+                    u32::MAX => {
+                        next[index] = output.len() as u32;
+                        next.extend([u32::MAX].repeat(width));
+                        output.push(None);
+                        output.len() - 1
+                    }
+                    child => child as usize,
+                };
+            }
+
+            output[state].get_or_insert((pattern.len(), *value, *kind));
+        }
+
+        // Fills in the missing transitions from the failure link of each
+        // state, breadth first so every link is to a state that is already
+        // complete. A state ends its own pattern and those of its link.
+        let mut fail = vec![0; output.len()];
+        let mut ends = vec![Vec::new(); output.len()];
+        let mut queue = VecDeque::new();
+
+        for next in &mut next[..width] {
+            match *next {
+                u32::MAX => *next = 0,
+                child => queue.push_back(child as usize),
+            }
+        }
+
+        while let Some(state) = queue.pop_front() {
+            let link = fail[state];
+            ends[state] = output[state]
+                .into_iter()
+                .chain(ends[link].clone())
+                .collect();
+
+            for class in 0..width {
+                let index = state * width + class;
+
+                match next[index] {
+                    u32::MAX => next[index] = next[link * width + class],
+                    child => {
+                        fail[child as usize] = next[link * width + class] as usize;
+                        queue.push_back(child as usize);
+                    }
+                }
+            }
+        }
+
+        let mut matches = Vec::new();
+        let ends = ends
+            .into_iter()
+            .map(|found| {
+                let start = matches.len() as u32;
+                matches.extend(found);
+                start..matches.len() as u32
+            })
+            .collect();
+
+        Self {
+            classes,
+            width,
+            next,
+            ends,
+            matches,
+            overlapping: vocabulary.is_overlapping(),
+        }
+    }
+
+    /// Every digit on a line, in the order they start in. Where patterns
+    /// start at the same place, only the longest counts, and without
+    /// overlapping, so does a pattern that starts inside one before it.
+    pub fn digits(&self, line: &str) -> Vec<u32> {
+        self.scan(line, |_, value, _| value)
+    }
+
+    /// The digits of [`Matcher::digits`], with where they are on the line.
+    pub fn tokens(&self, line: &str) -> Vec<Token> {
+        self.scan(line, |span, value, kind| Token {
+            text: line[span.clone()].to_string(),
+            span,
+            kind,
+            value,
+        })
+    }
+
+    fn scan<T>(&self, line: &str, token: impl Fn(Range<usize>, u32, Kind) -> T) -> Vec<T> {
+        let mut found = Vec::new();
+        let mut next_start = 0;
+
+        for (start, len, value, kind) in self.matches(line) {
+            // A short word can end before a longer one that starts earlier,
+            // or at the same place, which only the slower path sorts out.
+            if start < next_start {
+                return self.scan_sorted(line, token);
+            }
+
+            found.push(token(start..start + len, value, kind));
+            next_start = self.next_start(start, len);
+        }
+
+        found
+    }
+
+    fn scan_sorted<T>(&self, line: &str, token: impl Fn(Range<usize>, u32, Kind) -> T) -> Vec<T> {
+        let mut found = self
+            .matches(line)
+            .map(|(start, len, value, kind)| (start, Reverse(len), value, kind))
+            .collect::<Vec<_>>();
+
+        found.sort_unstable();
+
+        let mut next_start = 0;
+        found
+            .into_iter()
+            .filter(|&(start, Reverse(len), _, _)| {
+                let keep = start >= next_start;
+
+                if keep {
+                    next_start = self.next_start(start, len);
+                }
+
+                keep
+            })
+            .map(|(start, Reverse(len), value, kind)| token(start..start + len, value, kind))
+            .collect()
+    }
+
+    fn next_start(&self, start: usize, len: usize) -> usize {
+        match self.overlapping {
+            true => start + 1,
+            false => start + len,
+        }
+    }
+
+    /// Every pattern on the line as its start, length, value and kind, in
+    /// the order they end in.
+    fn matches<'a>(
+        &'a self,
+        line: &'a str,
+    ) -> impl Iterator<Item = (usize, usize, u32, Kind)> + 'a {
+        line.as_bytes()
+            .iter()
+            .enumerate()
+            .scan(0, move |state, (index, &byte)| {
+                let class = self.classes[byte as usize] as usize;
+                *state = self.next[*state * self.width + class] as usize;
+
+                let ends = self.ends[*state].clone();
+                Some((index, ends))
+            })
+            .flat_map(move |(index, ends)| {
+                self.matches[ends.start as usize..ends.end as usize]
+                    .iter()
+                    .map(move |&(len, value, kind)| (index + 1 - len, len, value, kind))
+            })
+    }
+}
+
+fn valid_value(input: &str) -> IResult<&str, u32> {
+    alt((
+        digit_value,
+        value(0, tag("zero")),
+        value(1, tag("one")),
+        value(2, tag("two")),
+        value(3, tag("three")),
+        value(4, tag("four")),
+        value(5, tag("five")),
+        value(6, tag("six")),
+        value(7, tag("seven")),
+        value(8, tag("eight")),
+        value(9, tag("nine")),
+    ))(input)
+}
+
+fn digit_value(input: &str) -> IResult<&str, u32> {
+    let parser = verify(anychar, |c| c.is_ascii_digit());
+    map(parser, |c| c.to_digit(10).unwrap())(input)
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    const WORDS: [&str; 10] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    let mut lines = Vec::new();
+
+    for _ in 0..size.max(1) {
+        let mut tokens = (0..rng.range(0..=5))
+            .map(|_| match rng.range(0..=2) {
+                0 => (0..rng.range(1..=4))
+                    .map(|_| rng.range(b'a'..=b'z') as char)
+                    .collect(),
+                1 => rng.pick(&WORDS).to_string(),
+                _ => rng.range(0..=9).to_string(),
+            })
+            .collect::<Vec<String>>();
+
+        // Part 1 needs at least one digit on every line.
+        let index = rng.range(0..=tokens.len());
+        tokens.insert(index, rng.range(1..=9).to_string());
+
+        lines.push(tokens.concat());
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{
+        bytes::complete::tag_no_case,
+        error::{Error, ErrorKind},
+    };
+
+    /// Every vocabulary word tried at every offset, which is slow but plainly
+    /// right, for checking the matcher against.
+    fn reference(input: &str, vocabulary: &Vocabulary) -> Vec<u32> {
+        let mut input = input;
+        let mut output = Vec::new();
+
+        while !input.is_empty() {
+            if let Ok((rest, value)) = reference_value(input, vocabulary).finish() {
+                output.push(value);
+
+                if !vocabulary.is_overlapping() {
+                    input = rest;
+                    continue;
+                }
+            }
+
+            // Words can start inside the one just found, so the scan moves on a
+            // single character at a time.
+            let mut chars = input.chars();
+            chars.next();
+            input = chars.as_str();
+        }
+
+        output
+    }
+
+    fn reference_value<'a>(input: &'a str, vocabulary: &Vocabulary) -> IResult<&'a str, u32> {
+        alt((
+            |input| reference_digit(input, vocabulary),
+            |input| reference_word(input, vocabulary),
+        ))(input)
+    }
+
+    fn reference_word<'a>(input: &'a str, vocabulary: &Vocabulary) -> IResult<&'a str, u32> {
+        let mut longest: IResult<&str, u32> =
+            Err(nom::Err::Error(Error::new(input, ErrorKind::Tag)));
+
+        for (word, digit) in vocabulary.words() {
+            let res: IResult<&str, u32> = match vocabulary.is_case_insensitive() {
+                true => value(digit, tag_no_case(word))(input),
+                false => value(digit, tag(word))(input),
+            };
+
+            match (&res, &longest) {
+                (Ok((rest, _)), Ok((left, _))) if rest.len() >= left.len() => {}
+                (Ok(_), _) => longest = res,
+                _ => {}
+            }
+        }
+
+        longest
+    }
+
+    fn reference_digit<'a>(input: &'a str, vocabulary: &Vocabulary) -> IResult<&'a str, u32> {
+        let parser = verify(anychar, |c| {
+            c.is_ascii_digit() || vocabulary.has_unicode_digits() && decimal_value(*c).is_some()
+        });
+        map(parser, |c| decimal_value(c).unwrap())(input)
+    }
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day01>();
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day01>();
+    }
+
+    #[test]
+    fn generated_inputs() {
+        common::generate::check::<Day01>();
+    }
+
+    #[test]
+    fn vocabularies() {
+        let line = "xtwone3fourzero8eighthree";
+        let english = Vocabulary::english();
+
+        assert_eq!(parser(line, &Vocabulary::digits()), vec![3, 8]);
+        assert_eq!(parser(line, &english), vec![2, 1, 3, 4, 0, 8, 8, 3]);
+        assert_eq!(parser("abc", &english), vec![]);
+        assert_eq!(parser("", &english), vec![]);
+        assert_eq!(parser("ONE2", &english), vec![2]);
+        assert_eq!(parser("ONE2", &english.ignoring_case()), vec![1, 2]);
+
+        let named = |name| Vocabulary::named(name).unwrap();
+        assert_eq!(parser("unzérohuitneuf", &named("french")), vec![1, 0, 8, 9]);
+        assert_eq!(parser("dreiundfünfzig", &named("german")), vec![3, 5]);
+        assert_eq!(parser("veintidos", &named("spanish")), vec![2]);
+        assert_eq!(parser("siete7ocho", &named("spanish")), vec![7, 7, 8]);
+
+        // Numerals are read whole and one after the other.
+        let roman = named("roman");
+        assert_eq!(parser("VIII", &roman), vec![8]);
+        assert_eq!(parser("XIV-IIII", &roman), vec![4, 3, 1]);
+        assert_eq!(parser("viii", &roman), vec![]);
+        assert_eq!(parser("viii", &roman.ignoring_case()), vec![8]);
+
+        let german = named("german").ignoring_case();
+        assert_eq!(parser("Fünf FÜNF fünf", &german), vec![5, 5, 5]);
+
+        let unicode = Vocabulary::english().with_unicode_digits();
+        assert_eq!(parser("４two٣é", &Vocabulary::english()), vec![2]);
+        assert_eq!(parser("４two٣é", &unicode), vec![4, 2, 3]);
+        assert_eq!(parser("𝟗۰", &unicode), vec![9, 0]);
+    }
+
+    #[test]
+    fn reports_lines() {
+        let lines = report("xtwone3\nabc\n7", &Vocabulary::english());
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0].tokens,
+            [
+                Token {
+                    span: 1..4,
+                    text: String::from("two"),
+                    kind: Kind::Word,
+                    value: 2
+                },
+                Token {
+                    span: 3..6,
+                    text: String::from("one"),
+                    kind: Kind::Word,
+                    value: 1
+                },
+                Token {
+                    span: 6..7,
+                    text: String::from("3"),
+                    kind: Kind::Digit,
+                    value: 3
+                },
+            ]
+        );
+        assert_eq!((lines[0].first, lines[0].last), (Some(2), Some(3)));
+        assert_eq!(lines[0].calibration, Some(23));
+
+        assert_eq!(lines[1].number, 2);
+        assert_eq!(lines[1].calibration, None);
+        assert!(lines[1].no_digits);
+
+        assert_eq!(lines[2].calibration, Some(77));
+
+        // Spans are byte offsets, whatever the width of the characters.
+        let lines = report("é٣x１", &Vocabulary::digits().with_unicode_digits());
+        let spans = lines[0].tokens.iter().map(|token| token.span.clone());
+        assert_eq!(spans.collect::<Vec<_>>(), [2..4, 5..8]);
+        assert_eq!(lines[0].tokens[1].text, "１");
+        assert_eq!(lines[0].calibration, Some(31));
+
+        // The report reads lines the way the solution does.
+        let example = Day01::EXAMPLES[1].input;
+        let sum = report(example, &Vocabulary::english())
+            .iter()
+            .filter_map(|line| line.calibration)
+            .sum::<u32>();
+        assert_eq!(sum, 281);
+    }
+
+    #[test]
+    fn matcher_agrees_with_reference() {
+        let mut rng = Rng::new(1);
+        let vocabularies = [
+            Vocabulary::digits(),
+            Vocabulary::english(),
+            Vocabulary::english().ignoring_case(),
+            Vocabulary::named("roman").unwrap(),
+            Vocabulary::named("german").unwrap().ignoring_case(),
+            Vocabulary::english().with_unicode_digits(),
+        ];
+        let matchers = vocabularies.map(|v| {
+            let matcher = Matcher::new(&v);
+            (v, matcher)
+        });
+
+        // Lines from the generator, and lines made of pieces of words that
+        // overlap in every way the vocabularies can.
+        let generated = common::generate::input::<Day01>(200, 7);
+        let pieces = [
+            "on", "ne", "tw", "th", "ree", "ei", "ght", "se", "ven", "ni", "1", "x", "ON", "Ne",
+            "I", "V", "i", "IX", "fü", "FÜ", "nf", "se", "chs", "３", "٣", "é", "𝟗",
+        ];
+        let random = (0..400).map(|_| {
+            (0..rng.range(0..=12))
+                .map(|_| *rng.pick(&pieces))
+                .collect::<String>()
+        });
+
+        for line in generated.lines().map(str::to_string).chain(random) {
+            // The benchmark's baseline reads the same English digits.
+            assert_eq!(matchers[1].1.digits(&line), parser_nom(&line), "{line}");
+
+            for (vocabulary, matcher) in &matchers {
+                assert_eq!(
+                    matcher.digits(&line),
+                    reference(&line, vocabulary),
+                    "{line}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! The decimal digits of every script, like the fullwidth `３` or the
+//! Arabic-Indic `٣`, which `char::to_digit` does not read.
+
+/// The zero of each run of ten decimal digits, general category `Nd`, as of
+/// Unicode 15.0. Every script's digits are contiguous and in order.
+const ZEROS: [u32; 68] = [
+    0x30, 0x660, 0x6F0, 0x7C0, 0x966, 0x9E6, 0xA66, 0xAE6, 0xB66, 0xBE6, 0xC66, 0xCE6, 0xD66,
+    0xDE6, 0xE50, 0xED0, 0xF20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80, 0x1A90,
+    0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10,
+    0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650,
+    0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E950,
+    0x1FBF0,
+];
+
+/// The value of a decimal digit of any script.
+pub fn decimal_value(c: char) -> Option<u32> {
+    let c = c as u32;
+    let zero = match ZEROS.binary_search(&c) {
+        Ok(index) => ZEROS[index],
+        Err(0) => return None,
+        Err(index) => ZEROS[index - 1],
+    };
+
+    (c - zero < 10).then_some(c - zero)
+}
+
+/// Every decimal digit of every script, with its value.
+pub fn decimal_digits() -> impl Iterator<Item = (char, u32)> {
+    ZEROS
+        .iter()
+        .flat_map(|&zero| (0..10).map(move |value| (char::from_u32(zero + value).unwrap(), value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_digits() {
+        assert_eq!(decimal_value('7'), Some(7));
+        assert_eq!(decimal_value('３'), Some(3));
+        assert_eq!(decimal_value('٩'), Some(9));
+        assert_eq!(decimal_value('۰'), Some(0));
+        assert_eq!(decimal_value('𝟠'), Some(8));
+        assert_eq!(decimal_value('a'), None);
+        assert_eq!(decimal_value('/'), None);
+        assert_eq!(decimal_value('Ⅷ'), None);
+        assert_eq!(decimal_value('\u{FF1A}'), None);
+
+        for (c, value) in decimal_digits() {
+            assert!(c.is_numeric(), "{c:?}");
+            assert_eq!(decimal_value(c), Some(value));
+        }
+
+        assert_eq!(decimal_digits().count(), 680);
+        assert!(ZEROS.windows(2).all(|pair| pair[1] - pair[0] >= 10));
+    }
+}
@@ -0,0 +1,259 @@
+use anyhow::{bail, Context};
+use serde::Deserialize;
+use std::{collections::BTreeMap, fs, path::Path, str::FromStr};
+
+const ENGLISH: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const FRENCH: [&str; 10] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+const GERMAN: [&str; 10] = [
+    "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const SPANISH: [&str; 10] = [
+    "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+/// There is no Roman numeral for zero.
+const ROMAN: [&str; 10] = ["", "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"];
+
+/// Names of the built-in vocabularies, for [`Vocabulary::named`].
+pub const NAMES: [&str; 6] = ["digits", "english", "french", "german", "spanish", "roman"];
+
+/// The digits a scan recognises spelled out, besides digit characters.
+/// Chosen by name or file with `aoc calibrate --vocabulary`; the puzzle's own
+/// answers are always those of [`Vocabulary::english`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+    case_insensitive: bool,
+    overlapping: bool,
+    unicode_digits: bool,
+}
+
+/// A vocabulary file, like
+///
+/// ```toml
+/// case_insensitive = true
+/// unicode_digits = true
+///
+/// [words]
+/// een = 1
+/// twee = 2
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    words: BTreeMap<String, u32>,
+    #[serde(default)]
+    case_insensitive: bool,
+    #[serde(default = "overlapping")]
+    overlapping: bool,
+    #[serde(default)]
+    unicode_digits: bool,
+}
+
+fn overlapping() -> bool {
+    true
+}
+
+impl Vocabulary {
+    fn spelled(words: &[&str]) -> Self {
+        Self {
+            words: words
+                .iter()
+                .zip(0..)
+                .filter(|(word, _)| !word.is_empty())
+                .map(|(word, digit)| (word.to_string(), digit))
+                .collect(),
+            case_insensitive: false,
+            overlapping: true,
+            unicode_digits: false,
+        }
+    }
+
+    /// Digit characters only.
+    pub fn digits() -> Self {
+        Self::spelled(&[])
+    }
+
+    /// Digit characters and the English words for them.
+    pub fn english() -> Self {
+        Self::spelled(&ENGLISH)
+    }
+
+    /// One of the built-in vocabularies, by one of [`NAMES`]. Roman numerals
+    /// are matched whole, so `VIII` is only an 8, while the words of the
+    /// languages can share letters, as in `twone`.
+    pub fn named(name: &str) -> Option<Self> {
+        let vocabulary = match name {
+            "digits" => Self::digits(),
+            "english" => Self::english(),
+            "french" => Self::spelled(&FRENCH),
+            "german" => Self::spelled(&GERMAN),
+            "spanish" => Self::spelled(&SPANISH),
+            "roman" => Self {
+                overlapping: false,
+                ..Self::spelled(&ROMAN)
+            },
+            _ => return None,
+        };
+
+        Some(vocabulary)
+    }
+
+    /// Parses a vocabulary file.
+    pub fn from_toml(data: &str) -> anyhow::Result<Self> {
+        let file = toml::from_str::<File>(data)?;
+
+        for (word, digit) in &file.words {
+            if word.is_empty() {
+                bail!("a word cannot be empty");
+            }
+
+            if *digit > 9 {
+                bail!("'{word}' spells {digit}, which is not a digit");
+            }
+        }
+
+        Ok(Self {
+            words: file.words.into_iter().collect(),
+            case_insensitive: file.case_insensitive,
+            overlapping: file.overlapping,
+            unicode_digits: file.unicode_digits,
+        })
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let data = fs::read_to_string(path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        Self::from_toml(&data).with_context(|| format!("could not parse {}", path.display()))
+    }
+
+    /// The same words, matched in any case.
+    pub fn ignoring_case(self) -> Self {
+        Self {
+            case_insensitive: true,
+            ..self
+        }
+    }
+
+    /// The same words, with the decimal digits of every script besides
+    /// `0` to `9`. For reading other documents, as the puzzle has only ASCII
+    /// digits and its answers never use this.
+    pub fn with_unicode_digits(self) -> Self {
+        Self {
+            unicode_digits: true,
+            ..self
+        }
+    }
+
+    /// The spelled-out digits, with their values.
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, digit)| (word.as_str(), *digit))
+    }
+
+    /// Whether words match in any case: ASCII letters in any mix of cases,
+    /// other letters all in the word's lower or upper case.
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Whether a word can start inside the one before it.
+    pub fn is_overlapping(&self) -> bool {
+        self.overlapping
+    }
+
+    pub fn has_unicode_digits(&self) -> bool {
+        self.unicode_digits
+    }
+}
+
+impl FromStr for Vocabulary {
+    type Err = anyhow::Error;
+
+    /// A built-in vocabulary by name, or a vocabulary file. Only something
+    /// that looks like a path, with a separator or a `.toml` extension, is
+    /// read as a file, so a misspelt name is reported as one.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(vocabulary) = Self::named(s) {
+            return Ok(vocabulary);
+        }
+
+        let path = Path::new(s);
+        let is_path = s.contains(['/', std::path::MAIN_SEPARATOR])
+            || path
+                .extension()
+                .is_some_and(|extension| extension == "toml");
+
+        if !is_path {
+            bail!(
+                "unknown vocabulary '{s}', expected one of {} or a .toml file",
+                NAMES.join(", ")
+            );
+        }
+
+        Self::load(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named() {
+        for name in NAMES {
+            assert!(Vocabulary::named(name).is_some(), "{name}");
+        }
+
+        let roman = Vocabulary::named("roman").unwrap();
+        assert_eq!(roman.words().next(), Some(("I", 1)));
+        assert_eq!(roman.words().count(), 9);
+        assert!(!roman.is_overlapping());
+
+        assert!(Vocabulary::named("klingon").is_none());
+        let res = "frnch".parse::<Vocabulary>().unwrap_err();
+        assert_eq!(
+            res.to_string(),
+            "unknown vocabulary 'frnch', expected one of digits, english, french, german, \
+             spanish, roman or a .toml file"
+        );
+
+        let res = "klingon.toml".parse::<Vocabulary>().unwrap_err();
+        assert_eq!(res.to_string(), "could not read klingon.toml");
+        assert!("./klingon".parse::<Vocabulary>().is_err());
+        assert_eq!("roman".parse::<Vocabulary>().unwrap(), roman);
+    }
+
+    #[test]
+    fn parses_files() {
+        let vocabulary = Vocabulary::from_toml(
+            r#"
+            case_insensitive = true
+            unicode_digits = true
+
+            [words]
+            een = 1
+            twee = 2
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            vocabulary.words().collect::<Vec<_>>(),
+            [("een", 1), ("twee", 2)]
+        );
+        assert!(vocabulary.is_case_insensitive());
+        assert!(vocabulary.is_overlapping());
+        assert!(vocabulary.has_unicode_digits());
+
+        let res = Vocabulary::from_toml("[words]\ntien = 10").unwrap_err();
+        assert_eq!(res.to_string(), "'tien' spells 10, which is not a digit");
+
+        assert!(Vocabulary::from_toml("[words]\n\"\" = 1").is_err());
+        assert!(Vocabulary::from_toml("[word]\neen = 1").is_err());
+    }
+}
@@ -0,0 +1,413 @@
+//! Day 2: Cube Conundrum. Games of cubes drawn from a bag, checked against
+//! the bag's contents.
+
+use anyhow::Context;
+use common::{
+    example,
+    example::Example,
+    generate::Rng,
+    parse::{parse_lines, parse_lines_lenient, IResult, ParseError},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, multispace0, newline, space0, u64},
+    combinator::{cut, eof, map, opt, verify},
+    error::context,
+    multi::{many0, many_till},
+    sequence::tuple,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day02.txt");
+    const EXAMPLES: &'static [Example] = &[example!("1.txt", Some("8"), Some("2286"))];
+
+    type Parsed = Vec<Game>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_lines(input, game_record)?)
+    }
+
+    fn parse_lenient(input: &str) -> anyhow::Result<(Self::Parsed, Vec<ParseError>)> {
+        Ok(parse_lines_lenient(input, game_record))
+    }
+
+    fn part1(games: &Self::Parsed) -> anyhow::Result<u64> {
+        games
+            .iter()
+            .filter(|game| {
+                let (red, green, blue) = game.max_cubes();
+                red <= 12 && green <= 13 && blue <= 14
+            })
+            .try_fold(0u64, |sum, game| {
+                sum.checked_add(game.id())
+                    .context("sum of game ids overflows")
+            })
+    }
+
+    fn part2(games: &Self::Parsed) -> anyhow::Result<u64> {
+        games.iter().try_fold(0u64, |sum, game| {
+            let (red, green, blue) = game.max_cubes();
+            let power = red
+                .checked_mul(green)
+                .and_then(|power| power.checked_mul(blue))
+                .with_context(|| format!("power of game {} overflows", game.id()))?;
+
+            sum.checked_add(power).context("sum of powers overflows")
+        })
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Colour {
+    Red,
+    Green,
+    Blue,
+}
+
+/// A number of cubes of one colour, as in `3 blue`.
+#[derive(Debug, PartialEq)]
+pub struct Cube(pub u64, pub Colour);
+
+/// A game, as its id and the sets of cubes revealed in it.
+#[derive(Debug, PartialEq)]
+pub struct Game(u64, Vec<Vec<Cube>>);
+
+impl Game {
+    pub fn id(&self) -> u64 {
+        self.0
+    }
+
+    pub fn sets(&self) -> &[Vec<Cube>] {
+        &self.1
+    }
+
+    /// The most red, green and blue cubes revealed at once, which is the
+    /// fewest the bag can hold for the game to be possible.
+    pub fn max_cubes(&self) -> (u64, u64, u64) {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        let cubes = self.1.iter().flatten();
+        for Cube(count, colour) in cubes {
+            let count = *count;
+
+            match colour {
+                Colour::Red => {
+                    if count > red {
+                        red = count;
+                    }
+                }
+                Colour::Green => {
+                    if count > green {
+                        green = count;
+                    }
+                }
+                Colour::Blue => {
+                    if count > blue {
+                        blue = count;
+                    }
+                }
+            }
+        }
+
+        (red, green, blue)
+    }
+}
+
+fn record_start(input: &str) -> IResult<&str, u64> {
+    let parser = tuple((multispace0, tag("Game"), space0, u64, char(':'), space0));
+    context("'Game <id>:'", map(parser, |(_, _, _, num, _, _)| num))(input)
+}
+
+fn game_record(input: &str) -> IResult<&str, Game> {
+    let parser = tuple((record_start, cube_set_terminators));
+    map(parser, |(id, sets)| Game(id, sets))(input)
+}
+
+fn red(input: &str) -> IResult<&str, Colour> {
+    map(tag("red"), |_| Colour::Red)(input)
+}
+
+fn green(input: &str) -> IResult<&str, Colour> {
+    map(tag("green"), |_| Colour::Green)(input)
+}
+
+fn blue(input: &str) -> IResult<&str, Colour> {
+    map(tag("blue"), |_| Colour::Blue)(input)
+}
+
+fn colour(input: &str) -> IResult<&str, Colour> {
+    alt((red, green, blue))(input)
+}
+
+fn cube(input: &str) -> IResult<&str, Cube> {
+    let parser = tuple((
+        u64,
+        space0,
+        cut(context("'red', 'green' or 'blue'", colour)),
+    ));
+    map(parser, |(num, _, colour)| Cube(num, colour))(input)
+}
+
+fn separator(input: &str) -> IResult<&str, ()> {
+    let parser = opt(tuple((char(','), space0)));
+    map(parser, |_| ())(input)
+}
+
+fn cube_separator(input: &str) -> IResult<&str, Cube> {
+    let parser = tuple((cube, space0, separator));
+    map(parser, |(cube, _, _)| cube)(input)
+}
+
+fn cube_set(input: &str) -> IResult<&str, Vec<Cube>> {
+    many0(cube_separator)(input)
+}
+
+fn terminator(input: &str) -> IResult<&str, ()> {
+    let parser = opt(tuple((char(';'), space0)));
+    map(parser, |_| ())(input)
+}
+
+fn cube_set_terminator(input: &str) -> IResult<&str, Vec<Cube>> {
+    let parser = tuple((cube_set, terminator));
+    map(parser, |(cubes, _)| cubes)(input)
+}
+
+fn cube_set_terminators(input: &str) -> IResult<&str, Vec<Vec<Cube>>> {
+    let newline = map(tuple((newline, multispace0)), |_| ());
+    let eof = map(eof, |_| ());
+    let end = alt((newline, eof));
+    let set = verify(cube_set_terminator, |cubes: &Vec<Cube>| !cubes.is_empty());
+    let parser = many_till(context("a cube count like '3 blue'", set), end);
+
+    map(parser, |(cubes, _)| cubes)(input)
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut lines = Vec::new();
+
+    for id in 1..=size.max(1) {
+        let sets = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colours = ["red", "green", "blue"];
+                rng.shuffle(&mut colours);
+
+                colours[..rng.range(1..=3)]
+                    .iter()
+                    .map(|colour| format!("{} {colour}", rng.range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+
+        lines.push(format!("Game {id}: {}", sets.join("; ")));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::Finish;
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day02>();
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day02>();
+    }
+
+    #[test]
+    fn generated_inputs() {
+        common::generate::check::<Day02>();
+    }
+
+    #[test]
+    fn parse_errors() {
+        let res = Day02::parse("Game 1: 3 blue\nGame 2: 8 gren, 6 blue").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (2, 11));
+        assert_eq!(res.expected, "'red', 'green' or 'blue'");
+
+        let res = Day02::parse("Game 1: 3 blue x").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (1, 16));
+
+        let res = Day02::parse("Gam 1: 3 blue").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!(res.expected, "'Game <id>:'");
+    }
+
+    #[test]
+    fn lenient_parse() {
+        let input = "Game 1: 3 blue\nGame 2: 8 gren\nGame 3: 20 red";
+        assert!(Day02::parse(input).is_err());
+
+        let (games, skipped) = Day02::parse_lenient(input).unwrap();
+        assert_eq!(Day02::part1(&games).unwrap(), 1);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].line, 2);
+    }
+
+    #[test]
+    fn individual_colour_test() {
+        let res = red("red").finish().unwrap();
+        assert_eq!(res, ("", Colour::Red));
+
+        let res = green("green").finish().unwrap();
+        assert_eq!(res, ("", Colour::Green));
+
+        let res = blue("blue").finish().unwrap();
+        assert_eq!(res, ("", Colour::Blue));
+    }
+
+    #[test]
+    fn colour_test() {
+        let res = colour("red").finish().unwrap();
+        assert_eq!(res, ("", Colour::Red));
+
+        let res = colour("green").finish().unwrap();
+        assert_eq!(res, ("", Colour::Green));
+
+        let res: (&str, Colour) = colour("blue").finish().unwrap();
+        assert_eq!(res, ("", Colour::Blue));
+    }
+
+    #[test]
+    fn cube_test() {
+        let res = cube("3 blue").finish().unwrap();
+        assert_eq!(res, ("", Cube(3, Colour::Blue)));
+
+        let res = cube("2 red").finish().unwrap();
+        assert_eq!(res, ("", Cube(2, Colour::Red)));
+    }
+
+    #[test]
+    fn cube_separator_test() {
+        let res = cube_separator("4 blue,").finish().unwrap();
+        assert_eq!(res, ("", Cube(4, Colour::Blue)));
+
+        let res = cube_separator("2 red").finish().unwrap();
+        assert_eq!(res, ("", Cube(2, Colour::Red)));
+
+        let res = cube_separator("1000 green,            ").finish().unwrap();
+        assert_eq!(res, ("", Cube(1000, Colour::Green)));
+
+        let res = cube_separator("9 green          ,            ")
+            .finish()
+            .unwrap();
+        assert_eq!(res, ("", Cube(9, Colour::Green)));
+    }
+
+    #[test]
+    fn cube_set_test() {
+        let res = cube_set("1 blue, 2 red, 3 green").finish().unwrap();
+        assert_eq!(
+            res,
+            (
+                "",
+                vec![
+                    Cube(1, Colour::Blue),
+                    Cube(2, Colour::Red),
+                    Cube(3, Colour::Green)
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn cube_set_terminator_test() {
+        let res = cube_set_terminator("1 blue, 2 red, 3 green;")
+            .finish()
+            .unwrap();
+        assert_eq!(
+            res,
+            (
+                "",
+                vec![
+                    Cube(1, Colour::Blue),
+                    Cube(2, Colour::Red),
+                    Cube(3, Colour::Green)
+                ]
+            )
+        );
+
+        let res = cube_set_terminator("1 blue, 2 red").finish().unwrap();
+        assert_eq!(
+            res,
+            ("", vec![Cube(1, Colour::Blue), Cube(2, Colour::Red),])
+        );
+
+        let res = cube_set_terminator("1 blue, 2 red;     3 green")
+            .finish()
+            .unwrap();
+        assert_eq!(
+            res,
+            (
+                "3 green",
+                vec![Cube(1, Colour::Blue), Cube(2, Colour::Red),]
+            )
+        );
+    }
+
+    #[test]
+    fn cube_set_terminators_test() {
+        let res = cube_set_terminators("1 blue, 2 red, 3 green; 4 red")
+            .finish()
+            .unwrap();
+        assert_eq!(
+            res,
+            (
+                "",
+                vec![
+                    vec![
+                        Cube(1, Colour::Blue),
+                        Cube(2, Colour::Red),
+                        Cube(3, Colour::Green)
+                    ],
+                    vec![Cube(4, Colour::Red)]
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn game_record_test() {
+        let res = game_record("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green    ")
+            .finish()
+            .unwrap();
+        assert_eq!(
+            res,
+            (
+                "",
+                Game(
+                    1,
+                    vec![
+                        vec![Cube(3, Colour::Blue), Cube(4, Colour::Red)],
+                        vec![
+                            Cube(1, Colour::Red),
+                            Cube(2, Colour::Green),
+                            Cube(6, Colour::Blue)
+                        ],
+                        vec![Cube(2, Colour::Green)]
+                    ]
+                )
+            )
+        )
+    }
+}
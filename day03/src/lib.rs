@@ -0,0 +1,292 @@
+//! Day 3: Gear Ratios. An engine schematic of part numbers and the symbols
+//! they are attached to.
+
+use anyhow::Context;
+use common::{example, example::Example, generate::Rng, parse::ParseError, Solution};
+use grid::{image::Rgb, Grid};
+use std::collections::HashSet;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day03.txt");
+    const EXAMPLES: &'static [Example] = &[example!("1.txt", Some("4361"), Some("467835"))];
+
+    type Parsed = Schematic;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(Schematic::parse(input)?)
+    }
+
+    fn part1(schematic: &Self::Parsed) -> anyhow::Result<u64> {
+        schematic
+            .part_numbers()
+            .into_iter()
+            .try_fold(0u64, |sum, number| {
+                sum.checked_add(number)
+                    .context("sum of part numbers overflows")
+            })
+    }
+
+    fn part2(schematic: &Self::Parsed) -> anyhow::Result<u64> {
+        schematic.gears().into_iter().try_fold(0u64, |sum, (a, b)| {
+            let ratio = a.checked_mul(b).context("gear ratio overflows")?;
+            sum.checked_add(ratio)
+                .context("sum of gear ratios overflows")
+        })
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Blank,
+    Symbol(char),
+    Digit(u64),
+    /// A digit of the part number with this index.
+    Number(usize),
+}
+
+/// An engine schematic, with every number on it resolved.
+#[derive(Debug)]
+pub struct Schematic {
+    grid: Grid<Cell>,
+    numbers: Vec<u64>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut grid = Grid::parse(input, "'.', a symbol or a part number", |c| match c {
+            '.' => Some(Cell::Blank),
+            '0'..='9' => c.to_digit(10).map(|d| Cell::Digit(d.into())),
+            c if c.is_alphanumeric() => None,
+            c => Some(Cell::Symbol(c)),
+        })?;
+
+        let mut numbers = Vec::new();
+
+        for y in 0..grid.height() {
+            let mut x = 0;
+
+            while x < grid.width() {
+                let start = x;
+                let mut value = None;
+
+                while let Cell::Digit(digit) = grid[(x, y)] {
+                    value = value
+                        .unwrap_or(0u64)
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(digit));
+
+                    if value.is_none() {
+                        let line = input.lines().nth(y).unwrap_or_default();
+                        let offset = line.char_indices().nth(start).map_or(0, |(i, _)| i);
+
+                        return Err(ParseError::at(
+                            line,
+                            offset,
+                            "a part number that fits in 64 bits",
+                        )
+                        .on_line(y + 1));
+                    }
+
+                    grid[(x, y)] = Cell::Number(numbers.len());
+                    x += 1;
+
+                    if x == grid.width() {
+                        break;
+                    }
+                }
+
+                match value {
+                    Some(value) => numbers.push(value),
+                    None => x += 1,
+                }
+            }
+        }
+
+        Ok(Self { grid, numbers })
+    }
+
+    /// Every symbol with its position.
+    pub fn symbols(&self) -> impl Iterator<Item = ((usize, usize), char)> + '_ {
+        self.grid.iter().filter_map(|(position, cell)| match cell {
+            Cell::Symbol(symbol) => Some((position, *symbol)),
+            _ => None,
+        })
+    }
+
+    /// The numbers next to a symbol, each counted once, in the order the
+    /// symbols appear.
+    pub fn part_numbers(&self) -> Vec<u64> {
+        let mut seen = HashSet::new();
+
+        self.symbols()
+            .flat_map(|((x, y), _)| self.adjacent_numbers(x, y))
+            .filter(|id| seen.insert(*id))
+            .map(|id| self.numbers[id])
+            .collect()
+    }
+
+    /// The two part numbers of every gear, a `*` next to exactly two numbers.
+    pub fn gears(&self) -> Vec<(u64, u64)> {
+        self.gear_ids()
+            .map(|(_, (a, b))| (self.numbers[a], self.numbers[b]))
+            .collect()
+    }
+
+    /// The schematic as an image: part numbers in green and other numbers in
+    /// grey, with gears in red and their two numbers in yellow.
+    pub fn render(&self) -> Grid<Rgb> {
+        let parts = self
+            .symbols()
+            .flat_map(|((x, y), _)| self.adjacent_numbers(x, y))
+            .collect::<HashSet<_>>();
+
+        let mut gears = HashSet::new();
+        let mut gear_numbers = HashSet::new();
+
+        for (position, (a, b)) in self.gear_ids() {
+            gears.insert(position);
+            gear_numbers.extend([a, b]);
+        }
+
+        let mut image = self.grid.map(|cell| match cell {
+            Cell::Blank => Rgb::BLACK,
+            Cell::Symbol(_) => Rgb::WHITE,
+            Cell::Number(id) if gear_numbers.contains(id) => Rgb::YELLOW,
+            Cell::Number(id) if parts.contains(id) => Rgb::GREEN,
+            Cell::Number(_) | Cell::Digit(_) => Rgb::GREY,
+        });
+
+        for position in gears {
+            image[position] = Rgb::RED;
+        }
+
+        image
+    }
+
+    /// The position of every gear, with the indices of its two numbers.
+    fn gear_ids(&self) -> impl Iterator<Item = ((usize, usize), (usize, usize))> + '_ {
+        self.symbols()
+            .filter(|(_, symbol)| *symbol == '*')
+            .filter_map(|((x, y), _)| {
+                let mut ids = Vec::new();
+
+                for id in self.adjacent_numbers(x, y) {
+                    if !ids.contains(&id) {
+                        ids.push(id);
+                    }
+                }
+
+                match ids[..] {
+                    [a, b] => Some(((x, y), (a, b))),
+                    _ => None,
+                }
+            })
+    }
+
+    /// Indices of the part numbers next to `(x, y)`, with a number repeated
+    /// for every one of its digits that touches the cell.
+    fn adjacent_numbers(&self, x: usize, y: usize) -> impl Iterator<Item = usize> + '_ {
+        self.grid
+            .neighbours8(x, y)
+            .filter_map(|(x, y)| match self.grid[(x, y)] {
+                Cell::Number(id) => Some(id),
+                _ => None,
+            })
+    }
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    const SYMBOLS: &[u8] = b"**#+$/@=%-&";
+
+    let width = size.max(1);
+    let mut lines = Vec::new();
+
+    for _ in 0..width {
+        let mut line = String::new();
+
+        while line.len() < width {
+            if rng.chance(0.15) {
+                let digits = rng.range(1..=(width - line.len()).min(3)) as u32;
+                let number = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+                line.push_str(&number.to_string());
+
+                // Keep the next number from running into this one.
+                if line.len() < width {
+                    line.push('.');
+                }
+            } else if rng.chance(0.1) {
+                line.push(*rng.pick(SYMBOLS) as char);
+            } else {
+                line.push('.');
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day03>();
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day03>();
+    }
+
+    #[test]
+    fn generated_inputs() {
+        common::generate::check::<Day03>();
+    }
+
+    #[test]
+    fn render() {
+        let schematic = Day03::parse(Day03::EXAMPLES[0].input).unwrap();
+        let image = schematic.render();
+        let count = |colour| image.iter().filter(|(_, c)| **c == colour).count();
+
+        assert_eq!((image.width(), image.height()), (10, 10));
+        assert_eq!(image[(3, 1)], Rgb::RED);
+        assert_eq!(image[(0, 0)], Rgb::YELLOW);
+        assert_eq!(image[(5, 0)], Rgb::GREY);
+        assert_eq!(image[(6, 2)], Rgb::GREEN);
+
+        // Two gears with two numbers each, and two numbers next to nothing.
+        assert_eq!(count(Rgb::RED), 2);
+        assert_eq!(count(Rgb::YELLOW), 11);
+        assert_eq!(count(Rgb::GREY), 5);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let res = Day03::parse("467..114..\n...*..a...").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (2, 7));
+        assert_eq!(res.expected, "'.', a symbol or a part number");
+
+        let res = Day03::parse("..*.......\n.123456789012345678901").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (2, 11));
+        assert_eq!(res.expected, "a row of 10 cells");
+
+        let res = Day03::parse("*.......................\n.123456789012345678901..").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (2, 2));
+        assert_eq!(res.expected, "a part number that fits in 64 bits");
+    }
+}
@@ -0,0 +1,213 @@
+//! Day 4: Scratchcards. Cards of winning numbers and the numbers scratched
+//! off them.
+
+use anyhow::Context;
+use common::{
+    example,
+    example::Example,
+    generate::Rng,
+    parse::{parse_lines, parse_lines_lenient, IResult, ParseError},
+    Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, space0, u64},
+    combinator::map,
+    error::context,
+    multi::many0,
+    sequence::tuple,
+};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day04.txt");
+    const EXAMPLES: &'static [Example] = &[example!("1.txt", Some("13"), Some("30"))];
+
+    type Parsed = Vec<Card>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_lines(input, card)?)
+    }
+
+    fn parse_lenient(input: &str) -> anyhow::Result<(Self::Parsed, Vec<ParseError>)> {
+        Ok(parse_lines_lenient(input, card))
+    }
+
+    fn part1(cards: &Self::Parsed) -> anyhow::Result<u64> {
+        cards.iter().try_fold(0u64, |sum, card| {
+            card.points()
+                .and_then(|points| sum.checked_add(points))
+                .context("sum of points overflows")
+        })
+    }
+
+    fn part2(cards: &Self::Parsed) -> anyhow::Result<u64> {
+        let mut map = HashMap::new();
+
+        for card in cards {
+            let count = map.entry(card.id()).or_insert(0u64);
+            *count = count
+                .checked_add(1)
+                .context("number of card copies overflows")?;
+            let card_count = *count;
+
+            for win_id in card.win_set().context("card ids overflow")? {
+                let count = map.entry(win_id).or_insert(0u64);
+                *count = count
+                    .checked_add(card_count)
+                    .context("number of card copies overflows")?;
+            }
+        }
+
+        map.values()
+            .try_fold(0u64, |sum, count| sum.checked_add(*count))
+            .context("total number of cards overflows")
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+#[derive(Debug)]
+pub struct Card {
+    id: u64,
+    winning: HashSet<u64>,
+    numbers: HashSet<u64>,
+}
+
+impl Card {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// The card's worth: one point for the first winning number it has,
+    /// doubled for each one after that. `None` if that overflows.
+    pub fn points(&self) -> Option<u64> {
+        match self.wins() {
+            0 => Some(0),
+            wins => 2u64.checked_pow((wins - 1) as u32),
+        }
+    }
+
+    /// How many of the card's numbers are winning numbers.
+    pub fn wins(&self) -> u64 {
+        self.numbers.intersection(&self.winning).count() as u64
+    }
+
+    /// Ids of the cards this card wins copies of, the ones right after it.
+    pub fn win_set(&self) -> Option<Vec<u64>> {
+        let count = self.wins();
+        let start = self.id.checked_add(1)?;
+        Some((start..start.checked_add(count)?).collect())
+    }
+}
+
+fn card(input: &str) -> IResult<&str, Card> {
+    let parser = tuple((
+        context("'Card'", tag("Card")),
+        space0,
+        context("a card id", u64),
+        context("':'", char(':')),
+        space0,
+        numbers,
+        separator,
+        numbers,
+    ));
+
+    map(parser, |(_, _, id, _, _, winning, _, numbers)| Card {
+        id,
+        winning,
+        numbers,
+    })(input)
+}
+
+fn numbers(input: &str) -> IResult<&str, HashSet<u64>> {
+    let nums = map(tuple((u64, space0)), |(num, _)| num);
+    let parser = many0(nums);
+    map(parser, |n| n.into_iter().collect::<HashSet<_>>())(input)
+}
+
+fn separator(input: &str) -> IResult<&str, ()> {
+    let parser = tuple((char('|'), space0));
+    context("a number or '|'", map(parser, |_| ()))(input)
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    const WINNING: usize = 10;
+    const NUMBERS: usize = 25;
+
+    let cards = size.max(1);
+    let mut lines = Vec::new();
+
+    for id in 1..=cards {
+        // Cards mostly lose, so the copies in part 2 grow slower than the
+        // cards themselves, and never reach past the last card.
+        let wins = match rng.chance(0.6) {
+            true => 0,
+            false => rng.range(1..=3),
+        };
+        let wins = wins.min(cards - id);
+
+        let mut pool = (1..=99).collect::<Vec<u64>>();
+        rng.shuffle(&mut pool);
+
+        let winning = &pool[..WINNING];
+        let mut numbers = winning[..wins].to_vec();
+        numbers.extend(&pool[WINNING..WINNING + NUMBERS - wins]);
+        rng.shuffle(&mut numbers);
+
+        let column = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        lines.push(format!(
+            "Card {id:>3}: {} | {}",
+            column(winning),
+            column(&numbers)
+        ));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day04>();
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day04>();
+    }
+
+    #[test]
+    fn generated_inputs() {
+        common::generate::check::<Day04>();
+    }
+
+    #[test]
+    fn parse_errors() {
+        let res = Day04::parse("Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (2, 12));
+        assert_eq!(res.expected, "a number or '|'");
+
+        let res = Day04::parse("Card 1: 41 48 | 83 86 !").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (1, 23));
+        assert_eq!(res.expected, "end of line");
+    }
+}
@@ -0,0 +1,309 @@
+//! Day 5: If You Give A Seed A Fertilizer. An almanac of seeds and the maps
+//! that lead from each seed to a location.
+
+use anyhow::Context;
+use common::{
+    example,
+    example::Example,
+    generate::Rng,
+    parse::{failure, parse_all, IResult},
+    Solution,
+};
+use nom::{
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, i64, multispace0, space0},
+    combinator::{cut, map, value},
+    error::context,
+    multi::many0,
+    sequence::tuple,
+};
+use rayon::prelude::*;
+use std::ops::Range;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day05.txt");
+    const EXAMPLES: &'static [Example] = &[example!("1.txt", Some("35"), Some("46"))];
+
+    type Parsed = SoilData;
+    type Answer = i64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        parser(input)
+    }
+
+    fn part1(soil_data: &Self::Parsed) -> anyhow::Result<i64> {
+        soil_data
+            .location_from_slice(soil_data.seeds())
+            .context("there are no seeds")
+    }
+
+    fn part2(soil_data: &Self::Parsed) -> anyhow::Result<i64> {
+        soil_data
+            .seed_ranges()?
+            .par_iter()
+            .filter_map(|seeds| soil_data.location_from_range(seeds.clone()))
+            .min()
+            .context("there are no seeds")
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+/// The almanac: the seeds to plant and the maps from seed to soil, soil to
+/// fertilizer and so on, in order, down to location.
+#[derive(Debug, Default)]
+pub struct SoilData {
+    seeds: Vec<i64>,
+    maps: Vec<Vec<MapValue>>,
+}
+
+impl SoilData {
+    pub fn seeds(&self) -> &[i64] {
+        &self.seeds
+    }
+
+    /// The maps, each a list of ranges.
+    pub fn maps(&self) -> &[Vec<MapValue>] {
+        &self.maps
+    }
+
+    /// The lowest location of any of `seeds`, or `None` without seeds.
+    pub fn location_from_slice(&self, seeds: &[i64]) -> Option<i64> {
+        seeds.iter().map(|seed| self.map_seed(*seed)).min()
+    }
+
+    /// The lowest location of any seed in `seeds`, or `None` if it is empty.
+    pub fn location_from_range(&self, seeds: Range<i64>) -> Option<i64> {
+        seeds.into_iter().map(|seed| self.map_seed(seed)).min()
+    }
+
+    /// Follows a seed through every map to its location. Values that no
+    /// range of a map covers keep their number.
+    pub fn map_seed(&self, seed: i64) -> i64 {
+        let mut value = seed;
+
+        for section in &self.maps {
+            let res = section.iter().filter_map(|m| m.mapped_value(value)).next();
+            if let Some(v) = res {
+                value = v;
+            }
+        }
+
+        value
+    }
+
+    /// The seeds read as pairs of a range start and length, as in part 2.
+    pub fn seed_ranges(&self) -> anyhow::Result<Vec<Range<i64>>> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| {
+                let (start, length) = (pair[0], pair[1]);
+                let end = start
+                    .checked_add(length)
+                    .with_context(|| format!("seed range {start} + {length} overflows"))?;
+
+                Ok(start..end)
+            })
+            .collect()
+    }
+}
+
+/// One line of a map, which moves a range of source values onto a range of
+/// destination values of the same length.
+#[derive(Debug, Default)]
+pub struct MapValue {
+    destination_range: Range<i64>,
+    source_range: Range<i64>,
+}
+
+impl MapValue {
+    /// `None` if either range would reach past `i64::MAX`.
+    pub fn new(destination_range_start: i64, source_range_start: i64, length: i64) -> Option<Self> {
+        let destination_range =
+            destination_range_start..destination_range_start.checked_add(length)?;
+        let source_range = source_range_start..source_range_start.checked_add(length)?;
+
+        Some(Self {
+            destination_range,
+            source_range,
+        })
+    }
+
+    /// Where `value` maps to, or `None` if it is outside the source range.
+    pub fn mapped_value(&self, value: i64) -> Option<i64> {
+        if self.source_range.contains(&value) {
+            Some(self.destination_range.start + (value - self.source_range.start))
+        } else {
+            None
+        }
+    }
+}
+
+fn parser(input: &str) -> anyhow::Result<SoilData> {
+    Ok(parse_all(input, soil_data)?)
+}
+
+fn soil_data(input: &str) -> IResult<&str, SoilData> {
+    let section_parser = map(tuple((map_names_and_numbers, multispace0)), |(data, _)| {
+        data
+    });
+    let parser = tuple((seeds_parser, multispace0, many0(section_parser)));
+    let (input, (seeds, sections)) = map(parser, |(seeds, _, sections)| (seeds, sections))(input)?;
+
+    Ok((
+        input,
+        SoilData {
+            seeds,
+            maps: sections,
+        },
+    ))
+}
+
+fn seeds_parser(input: &str) -> IResult<&str, Vec<i64>> {
+    let parser = tuple((
+        context("'seeds'", tag("seeds")),
+        context("':'", char(':')),
+        space0,
+        seed_numbers,
+    ));
+    map(parser, |(_, _, _, nums)| nums)(input)
+}
+
+fn seed_numbers(input: &str) -> IResult<&str, Vec<i64>> {
+    let parser = map(tuple((i64, space0)), |(num, _)| num);
+    many0(parser)(input)
+}
+
+fn map_end(input: &str) -> IResult<&str, ()> {
+    value((), tuple((space0, tag("map:"), space0)))(input)
+}
+
+fn name_parser(input: &str) -> IResult<&str, &str> {
+    take_till1(|c: char| c.is_whitespace())(input)
+}
+
+fn map_name(input: &str) -> IResult<&str, ()> {
+    let parser = tuple((name_parser, space0, cut(context("'map:'", map_end))));
+    value((), parser)(input)
+}
+
+fn map_numbers(input: &str) -> IResult<&str, MapValue> {
+    let mut parser = tuple((
+        i64,
+        space0,
+        cut(context("a source range start", i64)),
+        space0,
+        cut(context("a range length", i64)),
+        multispace0,
+    ));
+    let (remaining, (one, _, two, _, three, _)) = parser(input)?;
+    let value = MapValue::new(one, two, three)
+        .ok_or_else(|| failure(input, "a range that fits in 64 bits"))?;
+
+    Ok((remaining, value))
+}
+
+fn map_names_and_numbers(input: &str) -> IResult<&str, Vec<MapValue>> {
+    let parser = tuple((map_name, multispace0, many0(map_numbers)));
+    map(parser, |(_, _, numbers)| numbers)(input)
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+    const LIMIT: i64 = 1 << 32;
+
+    let seeds = (0..size.max(1))
+        .map(|_| {
+            let length = rng.range(1..=10_000);
+            format!("{} {length}", rng.range(0..=LIMIT - length))
+        })
+        .collect::<Vec<_>>();
+
+    let mut sections = vec![format!("seeds: {}", seeds.join(" "))];
+
+    for name in MAPS {
+        // Every pair of cut points bounds a source range, so they never
+        // overlap each other, while destinations land anywhere.
+        let count = rng.range(1..=size.clamp(1, 30));
+        let mut cuts = Vec::new();
+        while cuts.len() < 2 * count {
+            let cut = rng.range(0..=LIMIT);
+            if !cuts.contains(&cut) {
+                cuts.push(cut);
+            }
+        }
+        cuts.sort();
+
+        let mut ranges = cuts
+            .chunks_exact(2)
+            .map(|pair| {
+                let (source, length) = (pair[0], pair[1] - pair[0]);
+                format!("{} {source} {length}", rng.range(0..=LIMIT - length))
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut ranges);
+
+        sections.push(format!("{name} map:\n{}", ranges.join("\n")));
+    }
+
+    sections.join("\n\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::parse::ParseError;
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day05>();
+    }
+
+    #[test]
+    fn maps_seeds() {
+        let almanac = Day05::parse(Day05::EXAMPLES[0].input).unwrap();
+        let locations = almanac
+            .seeds()
+            .iter()
+            .map(|seed| almanac.map_seed(*seed))
+            .collect::<Vec<_>>();
+
+        assert_eq!(locations, vec![82, 43, 86, 35]);
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day05>();
+    }
+
+    #[test]
+    fn generated_inputs() {
+        common::generate::check::<Day05>();
+    }
+
+    #[test]
+    fn parse_errors() {
+        let res = Day05::parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 x\n").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (4, 7));
+        assert_eq!(res.expected, "a range length");
+
+        let res = Day05::parse("seeds: 79 14\n\nseed-to-soil:\n50 98 2\n").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (3, 14));
+        assert_eq!(res.expected, "'map:'");
+    }
+}
@@ -0,0 +1,227 @@
+//! Day 6: Wait For It. Boat races, and the ways to hold the button long
+//! enough to beat each record.
+
+use anyhow::Context;
+use common::{
+    example,
+    example::Example,
+    generate::Rng,
+    parse::{parse_all, IResult, ParseError},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::take_while1,
+    character::complete::{alpha0, char, i64, line_ending, space0},
+    combinator::{eof, map, map_res, value},
+    error::context,
+    multi::many0,
+    sequence::tuple,
+};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day06.txt");
+    const EXAMPLES: &'static [Example] = &[example!("1.txt", Some("288"), Some("71503"))];
+
+    type Parsed = Races;
+    type Answer = i64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(Races {
+            races: parser_01(input)?,
+            single: parser_02(input)?,
+        })
+    }
+
+    fn part1(data: &Self::Parsed) -> anyhow::Result<i64> {
+        data.races
+            .iter()
+            .try_fold(1i64, |product, d| product.checked_mul(d.ways_to_win()))
+            .context("product of ways to win overflows")
+    }
+
+    fn part2(data: &Self::Parsed) -> anyhow::Result<i64> {
+        Ok(data.single.ways_to_win())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+/// The sheet of races, read both as separate races and, with the spaces
+/// between the numbers ignored, as one long race.
+#[derive(Debug)]
+pub struct Races {
+    races: Vec<TimeDistance>,
+    single: TimeDistance,
+}
+
+impl Races {
+    pub fn races(&self) -> &[TimeDistance] {
+        &self.races
+    }
+
+    pub fn single(&self) -> &TimeDistance {
+        &self.single
+    }
+}
+
+/// A race's time and the record distance to beat in it.
+#[derive(Debug)]
+pub struct TimeDistance {
+    pub time: i64,
+    pub distance: i64,
+}
+
+impl TimeDistance {
+    pub fn new(time: i64, distance: i64) -> Self {
+        Self { time, distance }
+    }
+
+    /// How many whole milliseconds of holding the button beat the record.
+    pub fn ways_to_win(&self) -> i64 {
+        // Wide enough that the discriminant of any 64-bit race fits.
+        let a: i128 = -1;
+        let b = self.time as i128;
+        let c = -(self.distance as i128) - 1;
+
+        let numerator_val = ((b.pow(2) - 4 * a * c) as f64).sqrt();
+        let divisor = 2.0 * a as f64;
+
+        let x0 = (-b as f64 + numerator_val) / divisor;
+        let x1 = (-b as f64 - numerator_val) / divisor;
+
+        let ret = (x0.ceil() - x1.floor()).abs() + 1.0;
+
+        ret as i64
+    }
+}
+
+fn parser_01(input: &str) -> Result<Vec<TimeDistance>, ParseError> {
+    let (time, distance) = parse_all(input, time_distance_parser)?;
+
+    if time.len() != distance.len() {
+        let offset = input.find('\n').map(|i| i + 1).unwrap_or(input.len());
+        let expected = format!("{} distance(s), one per race time", time.len());
+        return Err(ParseError::at(input, offset, expected));
+    }
+
+    Ok(time
+        .iter()
+        .zip(distance.iter())
+        .map(|(t, d)| TimeDistance::new(*t, *d))
+        .collect())
+}
+
+fn parser_02(input: &str) -> Result<TimeDistance, ParseError> {
+    let (time, distance) = parse_all(input, time_distance_parser_single)?;
+
+    Ok(TimeDistance::new(time, distance))
+}
+
+fn time_distance_parser(input: &str) -> IResult<&str, (Vec<i64>, Vec<i64>)> {
+    tuple((data_parser, data_parser))(input)
+}
+
+fn time_distance_parser_single(input: &str) -> IResult<&str, (i64, i64)> {
+    tuple((single_value_parser, single_value_parser))(input)
+}
+
+fn identifier(input: &str) -> IResult<&str, &str> {
+    let parser = map(tuple((alpha0, char(':'))), |(name, _)| name);
+    context("a label like 'Time:'", parser)(input)
+}
+
+fn line_end_or_eof(input: &str) -> IResult<&str, ()> {
+    let parser = alt((value((), line_ending), value((), eof)));
+    context("a number or end of line", parser)(input)
+}
+
+fn data_parser(input: &str) -> IResult<&str, Vec<i64>> {
+    let numbers = many0(map(tuple((i64, space0)), |(nums, _)| nums));
+    let parser = tuple((identifier, space0, numbers));
+    let parser_nums = map(parser, |(_, _, nums)| nums);
+
+    map(tuple((parser_nums, line_end_or_eof)), |(data, _)| data)(input)
+}
+
+fn string_numbers(input: &str) -> IResult<&str, Vec<&str>> {
+    let parser = tuple((take_while1(|c: char| c.is_ascii_digit()), space0));
+
+    many0(map(parser, |(nums, _)| nums))(input)
+}
+
+fn single_value_parser_string(input: &str) -> IResult<&str, String> {
+    let parser = tuple((identifier, space0, string_numbers));
+    map(parser, |(_, _, num_strs)| num_strs.join(""))(input)
+}
+
+fn single_value_parser(input: &str) -> IResult<&str, i64> {
+    let parser = tuple((single_value_parser_string, line_end_or_eof));
+    let parser = map_res(parser, |(val, _)| val.parse::<i64>());
+    context("a number that fits in 64 bits", parser)(input)
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    // Part 2 joins the digits of every race, so only a few races fit.
+    let races = (0..size.clamp(1, 4))
+        .map(|_| {
+            let time = rng.range(7..=99);
+            (time, rng.range(time..=time * time / 4 - 1))
+        })
+        .collect::<Vec<(i64, i64)>>();
+
+    let line = |label: &str, values: Vec<i64>| {
+        let values = values
+            .iter()
+            .map(|value| format!("{value:>7}"))
+            .collect::<String>();
+        format!("{label:<9}{values}")
+    };
+
+    format!(
+        "{}\n{}\n",
+        line("Time:", races.iter().map(|race| race.0).collect()),
+        line("Distance:", races.iter().map(|race| race.1).collect())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day06>();
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day06>();
+    }
+
+    #[test]
+    fn generated_inputs() {
+        common::generate::check::<Day06>();
+    }
+
+    #[test]
+    fn parse_errors() {
+        let res = Day06::parse("Time: 7 15 x\nDistance: 9 40").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (1, 12));
+        assert_eq!(res.expected, "a number or end of line");
+
+        let res = Day06::parse("Time: 7 15 30\nDistance: 9 40").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (2, 1));
+
+        let res = Day06::parse("Time: 9999999999 9999999999\nDistance: 1 2").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!(res.expected, "a number that fits in 64 bits");
+    }
+}
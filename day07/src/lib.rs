@@ -0,0 +1,405 @@
+//! Day 7: Camel Cards. Poker-like hands, ranked by type and then card by
+//! card, with or without jokers.
+
+use anyhow::Context;
+use common::{
+    example,
+    example::Example,
+    generate::Rng,
+    parse::{parse_lines, parse_lines_lenient, IResult, ParseError},
+    Solution,
+};
+use core::panic;
+use nom::{
+    branch::alt,
+    character::complete::{anychar, char, space0, u64},
+    combinator::{map, value, verify},
+    error::context,
+    multi::count,
+    sequence::tuple,
+};
+use std::{
+    cmp::{Ord, Ordering},
+    collections::HashMap,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day07.txt");
+    const EXAMPLES: &'static [Example] = &[example!("1.txt", Some("6440"), Some("5905"))];
+
+    type Parsed = Vec<([u8; 5], u64)>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_lines(input, hand_bid)?)
+    }
+
+    fn parse_lenient(input: &str) -> anyhow::Result<(Self::Parsed, Vec<ParseError>)> {
+        Ok(parse_lines_lenient(input, hand_bid))
+    }
+
+    fn part1(hands: &Self::Parsed) -> anyhow::Result<u64> {
+        let hands = hands
+            .iter()
+            .map(|(cards, bid)| Hand::new(*cards, *bid))
+            .collect();
+
+        winnings(hands)
+    }
+
+    fn part2(hands: &Self::Parsed) -> anyhow::Result<u64> {
+        let hands = hands
+            .iter()
+            .map(|(cards, bid)| Hand::new_wildcard(*cards, *bid))
+            .collect();
+
+        winnings(hands)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+/// The total winnings of a set of hands: each hand's bid times its rank,
+/// from 1 for the weakest hand.
+pub fn winnings(mut hands: Vec<Hand>) -> anyhow::Result<u64> {
+    hands.sort();
+
+    hands
+        .iter()
+        .enumerate()
+        .try_fold(0u64, |sum, (index, hand)| {
+            let multiplier = (index + 1) as u64;
+            hand.bid
+                .checked_mul(multiplier)
+                .and_then(|winning| sum.checked_add(winning))
+        })
+        .context("total winnings overflow")
+}
+
+/// The type of a hand, from weakest to strongest.
+#[derive(Debug, Ord, PartialEq, PartialOrd, Eq)]
+pub enum Class {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// A hand with its bid. Hands order by strength: by class first, then by
+/// the first card that differs.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Hand {
+    cards: [u8; 5],
+    class: Class,
+    bid: u64,
+}
+
+#[derive(Debug, Ord, PartialEq, PartialOrd, Eq)]
+struct CountCard(u8, u8);
+
+impl CountCard {
+    fn count(&self) -> u8 {
+        self.0
+    }
+
+    fn card(&self) -> u8 {
+        self.1
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.class.cmp(&other.class) {
+            Ordering::Equal => self
+                .cards
+                .iter()
+                .zip(other.cards.iter())
+                .filter_map(|(left, right)| match left.cmp(right) {
+                    Ordering::Equal => None,
+                    ord => Some(ord),
+                })
+                .next()
+                .unwrap_or(Ordering::Equal),
+            ord => ord,
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hand {
+    /// A hand of card values, 2 to 9 for the number cards, then 10 for `T`
+    /// up to 14 for `A`.
+    pub fn new(cards: [u8; 5], bid: u64) -> Self {
+        let class = Self::classify_hand(&cards);
+
+        Self { cards, class, bid }
+    }
+
+    /// Like [`Hand::new`], but `J` (11) is a joker: the weakest card, which
+    /// stands in for whatever card makes the strongest class.
+    pub fn new_wildcard(cards: [u8; 5], bid: u64) -> Self {
+        let mut cards = cards;
+        for card in &mut cards {
+            if *card == 11 {
+                *card = 0;
+            }
+        }
+
+        let class = Self::classify_hand(&cards);
+        Self { cards, class, bid }
+    }
+
+    pub fn cards(&self) -> &[u8; 5] {
+        &self.cards
+    }
+
+    pub fn class(&self) -> &Class {
+        &self.class
+    }
+
+    pub fn bid(&self) -> u64 {
+        self.bid
+    }
+
+    /// The class of five card values, where 0 is a joker.
+    pub fn classify_hand(cards: &[u8; 5]) -> Class {
+        let counts = Self::card_counts(cards);
+        let has_wildcard = counts.iter().any(|c| c.card() == 0);
+
+        match counts.len() {
+            1 => Class::FiveOfAKind,
+            2 => match (counts[0].count(), counts[1].count()) {
+                (1, 4) => match has_wildcard {
+                    true => Class::FiveOfAKind,
+                    _ => Class::FourOfAKind,
+                },
+                (2, 3) => match has_wildcard {
+                    true => Class::FiveOfAKind,
+                    _ => Class::FullHouse,
+                },
+                _ => panic!("impossible 2 count"),
+            },
+            3 => match (counts[0].count(), counts[1].count(), counts[2].count()) {
+                (1, 1, 3) => match has_wildcard {
+                    true => Class::FourOfAKind,
+                    _ => Class::ThreeOfAKind,
+                },
+                (1, 2, 2) => match (counts[0].card(), counts[1].card(), counts[2].card()) {
+                    (0, _, _) => Class::FullHouse,
+                    (_, 0, _) => Class::FourOfAKind,
+                    (_, _, 0) => Class::FourOfAKind,
+                    _ => Class::TwoPair,
+                },
+                _ => panic!("impossible 3 count"),
+            },
+            4 => match has_wildcard {
+                true => Class::ThreeOfAKind,
+                _ => Class::OnePair,
+            },
+            5 => match has_wildcard {
+                true => Class::OnePair,
+                _ => Class::HighCard,
+            },
+            _ => panic!("impossible out of range"),
+        }
+    }
+
+    fn card_counts(cards: &[u8]) -> Vec<CountCard> {
+        let mut map = HashMap::new();
+
+        for card in cards {
+            if let Some(c) = map.get_mut(card) {
+                *c += 1;
+            } else {
+                map.insert(*card, 1u8);
+            }
+        }
+
+        let mut counts = map
+            .into_iter()
+            .map(|(card, count)| CountCard(count, card))
+            .collect::<Vec<_>>();
+        counts.sort();
+        counts
+    }
+}
+
+fn hand_bid(input: &str) -> IResult<&str, ([u8; 5], u64)> {
+    let parser = tuple((hand_raw, space0, context("a bid", u64)));
+    map(parser, |(hand, _, bid)| (hand, bid))(input)
+}
+
+fn hand_raw(input: &str) -> IResult<&str, [u8; 5]> {
+    let parser = count(context("a card (2-9, T, J, Q, K or A)", card), 5);
+    map(parser, |cards| {
+        [cards[0], cards[1], cards[2], cards[3], cards[4]]
+    })(input)
+}
+
+fn card(input: &str) -> IResult<&str, u8> {
+    alt((digit, face))(input)
+}
+
+fn face(input: &str) -> IResult<&str, u8> {
+    alt((
+        value(10, char('T')),
+        value(11, char('J')),
+        value(12, char('Q')),
+        value(13, char('K')),
+        value(14, char('A')),
+    ))(input)
+}
+
+fn digit(input: &str) -> IResult<&str, u8> {
+    let parser = verify(anychar, |c| *c != '0' && *c != '1' && c.is_ascii_digit());
+    map(parser, |c| c.to_digit(10).unwrap() as u8)(input)
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+
+    let mut lines = Vec::new();
+
+    for _ in 0..size.max(1) {
+        let hand = (0..5).map(|_| *rng.pick(CARDS) as char).collect::<String>();
+        lines.push(format!("{hand} {}", rng.range(1..=1000)));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day07>();
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day07>();
+    }
+
+    #[test]
+    fn generated_inputs() {
+        common::generate::check::<Day07>();
+    }
+
+    #[test]
+    fn parse_errors() {
+        let res = Day07::parse("32T3K 765\nT55X5 684").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (2, 4));
+        assert_eq!(res.expected, "a card (2-9, T, J, Q, K or A)");
+
+        let res = Day07::parse("32T3KK 765").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (1, 6));
+        assert_eq!(res.expected, "a bid");
+    }
+
+    #[test]
+    fn five_of_a_kind() {
+        let card = Hand::new_wildcard([2, 2, 2, 2, 2], 1);
+        assert_eq!(Class::FiveOfAKind, card.class);
+
+        let card = Hand::new_wildcard([2, 2, 2, 2, 11], 1);
+        assert_eq!(Class::FiveOfAKind, card.class);
+
+        let card = Hand::new_wildcard([2, 2, 2, 11, 11], 1);
+        assert_eq!(Class::FiveOfAKind, card.class);
+
+        let card = Hand::new_wildcard([2, 2, 11, 11, 11], 1);
+        assert_eq!(Class::FiveOfAKind, card.class);
+
+        let card = Hand::new_wildcard([2, 11, 11, 11, 11], 1);
+        assert_eq!(Class::FiveOfAKind, card.class);
+
+        let card = Hand::new_wildcard([11, 11, 11, 11, 11], 1);
+        assert_eq!(Class::FiveOfAKind, card.class);
+    }
+
+    #[test]
+    fn four_of_a_kind() {
+        let card = Hand::new_wildcard([4, 4, 4, 4, 2], 1);
+        assert_eq!(Class::FourOfAKind, card.class);
+
+        let card = Hand::new_wildcard([4, 4, 4, 11, 2], 1);
+        assert_eq!(Class::FourOfAKind, card.class);
+
+        let card = Hand::new_wildcard([4, 4, 11, 11, 2], 1);
+        assert_eq!(Class::FourOfAKind, card.class);
+
+        let card = Hand::new_wildcard([4, 11, 11, 11, 2], 1);
+        assert_eq!(Class::FourOfAKind, card.class);
+
+        let card = Hand::new_wildcard([3, 3, 11, 2, 11], 1);
+        assert_eq!(Class::FourOfAKind, card.class);
+    }
+
+    #[test]
+    fn full_house() {
+        let card = Hand::new_wildcard([3, 3, 3, 2, 2], 1);
+        assert_eq!(Class::FullHouse, card.class);
+
+        let card = Hand::new_wildcard([11, 3, 3, 2, 2], 1);
+        assert_eq!(Class::FullHouse, card.class);
+
+        let card = Hand::new_wildcard([3, 3, 11, 2, 2], 1);
+        assert_eq!(Class::FullHouse, card.class);
+    }
+
+    #[test]
+    fn three_of_a_kind() {
+        let card = Hand::new_wildcard([3, 3, 3, 2, 5], 1);
+        assert_eq!(Class::ThreeOfAKind, card.class);
+
+        let card = Hand::new_wildcard([11, 3, 3, 2, 5], 1);
+        assert_eq!(Class::ThreeOfAKind, card.class);
+
+        let card = Hand::new_wildcard([11, 11, 3, 2, 5], 1);
+        assert_eq!(Class::ThreeOfAKind, card.class);
+    }
+
+    #[test]
+    fn two_pair() {
+        let card = Hand::new_wildcard([2, 2, 3, 3, 4], 1);
+        assert_eq!(Class::TwoPair, card.class);
+
+        let card = Hand::new_wildcard([2, 3, 3, 5, 2], 1);
+        assert_eq!(Class::TwoPair, card.class);
+    }
+
+    #[test]
+    fn one_pair() {
+        let card = Hand::new_wildcard([2, 3, 4, 5, 5], 1);
+        assert_eq!(Class::OnePair, card.class);
+
+        let card = Hand::new_wildcard([2, 3, 4, 5, 11], 1);
+        assert_eq!(Class::OnePair, card.class);
+    }
+
+    #[test]
+    fn high_card() {
+        let card = Hand::new_wildcard([2, 3, 4, 5, 14], 1);
+        assert_eq!(Class::HighCard, card.class);
+
+        let card = Hand::new_wildcard([6, 3, 2, 8, 9], 1);
+        assert_eq!(Class::HighCard, card.class);
+    }
+}
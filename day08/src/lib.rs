@@ -0,0 +1,327 @@
+//! Day 8: Haunted Wasteland. A network of nodes, walked by following a list
+//! of left and right turns over and over.
+
+use anyhow::{bail, Context};
+use common::{
+    example,
+    example::Example,
+    generate::Rng,
+    parse::{parse_all, IResult},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    character::complete::{alphanumeric1, char, line_ending, multispace0, space0},
+    combinator::{cut, map, value},
+    error::context,
+    multi::many0,
+    sequence::tuple,
+};
+use num::Integer;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day08.txt");
+    const EXAMPLES: &'static [Example] = &[
+        example!("1.txt", Some("2"), None),
+        example!("2.txt", Some("6"), None),
+        example!("3.txt", None, Some("6")),
+    ];
+
+    type Parsed = MapData;
+    type Answer = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        parser(input)
+    }
+
+    fn part1(map_data: &Self::Parsed) -> anyhow::Result<u64> {
+        part01(map_data)
+    }
+
+    fn part2(map_data: &Self::Parsed) -> anyhow::Result<u64> {
+        part02(map_data)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+fn part01(map_data: &MapData) -> anyhow::Result<u64> {
+    let MapData(directions, map) = map_data;
+    let starts = ["AAA"];
+
+    solver(&starts, directions, map)
+}
+
+fn part02(map_data: &MapData) -> anyhow::Result<u64> {
+    let MapData(directions, map) = map_data;
+    let starts = &map
+        .keys()
+        .filter(|key| key.ends_with('A'))
+        .collect::<Vec<_>>();
+
+    if starts.is_empty() {
+        bail!("no node ends in 'A'");
+    }
+
+    solver(starts, directions, map)
+}
+
+/// Steps until every walk from `starts` is at a node ending in 'Z' at the
+/// same time, checked after each full pass over the directions. Each walk
+/// is assumed to cycle back through its first such node, as in the puzzle
+/// inputs, so the answer is the least common multiple of their lengths.
+pub fn solver<T>(
+    starts: &[T],
+    directions: &[Direction],
+    map: &HashMap<String, Mapping>,
+) -> anyhow::Result<u64>
+where
+    T: AsRef<str>,
+{
+    if directions.is_empty() {
+        bail!("there are no directions");
+    }
+
+    let len = directions.len() as u64;
+
+    starts
+        .iter()
+        .map(|s| s.as_ref())
+        .map(|start| {
+            let mut start = start;
+            let mut count = 0;
+            let mut destination = "";
+            // Nodes reached after each full pass over the directions. Seeing
+            // one again means the walk is in a loop that never ends in 'Z'.
+            let mut seen = HashSet::new();
+
+            while !destination.ends_with('Z') {
+                if !seen.insert(start) {
+                    bail!("the walk from {start} never reaches a node ending in 'Z'");
+                }
+
+                destination = directions.iter().try_fold(start, |location, direction| {
+                    let entry = map
+                        .get(location)
+                        .with_context(|| format!("there is no node {location}"))?;
+
+                    anyhow::Ok(match direction {
+                        Direction::Left => entry.left(),
+                        Direction::Right => entry.right(),
+                    })
+                })?;
+
+                count += len;
+                start = destination;
+            }
+
+            Ok(count)
+        })
+        .try_fold(1u64, |acc, count| {
+            let count = count?;
+            acc.checked_mul(count / acc.gcd(&count))
+                .context("number of steps overflows")
+        })
+}
+
+/// The nodes to the left and right of a node.
+#[derive(Debug, Clone)]
+pub struct Mapping(String, String);
+
+impl Mapping {
+    pub fn new(left: &str, right: &str) -> Self {
+        let left = left.to_string();
+        let right = right.to_string();
+
+        Self(left, right)
+    }
+
+    pub fn left(&self) -> &str {
+        &self.0
+    }
+
+    pub fn right(&self) -> &str {
+        &self.1
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// The list of turns and the network, by node name.
+#[derive(Debug)]
+pub struct MapData(Vec<Direction>, HashMap<String, Mapping>);
+
+impl MapData {
+    pub fn directions(&self) -> &[Direction] {
+        &self.0
+    }
+
+    pub fn nodes(&self) -> &HashMap<String, Mapping> {
+        &self.1
+    }
+}
+
+fn parser(input: &str) -> anyhow::Result<MapData> {
+    Ok(parse_all(input, data)?)
+}
+
+fn data(input: &str) -> IResult<&str, MapData> {
+    let end = context("'L', 'R' or a line break", line_ending);
+    let parser = tuple((directions, end, multispace0, many0(map_line)));
+
+    map(parser, |(directions, _, _, mappings)| {
+        MapData(directions, mappings.into_iter().collect::<HashMap<_, _>>())
+    })(input)
+}
+
+fn directions(input: &str) -> IResult<&str, Vec<Direction>> {
+    many0(direction)(input)
+}
+
+fn direction(input: &str) -> IResult<&str, Direction> {
+    alt((
+        value(Direction::Left, char('L')),
+        value(Direction::Right, char('R')),
+    ))(input)
+}
+
+fn map_line(input: &str) -> IResult<&str, (String, Mapping)> {
+    let parser = tuple((
+        alphanumeric1,
+        space0,
+        cut(context("'='", char('='))),
+        space0,
+        cut(context("'('", char('('))),
+        cut(context("a node name", alphanumeric1)),
+        space0,
+        cut(context("','", char(','))),
+        space0,
+        cut(context("a node name", alphanumeric1)),
+        space0,
+        cut(context("')'", char(')'))),
+        multispace0,
+    ));
+
+    map(
+        parser,
+        |(source, _, _, _, _, left, _, _, _, right, _, _, _)| {
+            let source: &str = source;
+            (source.to_string(), Mapping::new(left, right))
+        },
+    )(input)
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let len = size.clamp(1, 300);
+    let directions = (0..len)
+        .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+        .collect::<String>();
+
+    let mut used = HashSet::from([String::from("AAA"), String::from("ZZZ")]);
+    let mut name = |rng: &mut Rng, last: &[u8]| loop {
+        let name = format!(
+            "{}{}{}",
+            *rng.pick(LETTERS) as char,
+            *rng.pick(LETTERS) as char,
+            *rng.pick(last) as char
+        );
+
+        if used.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    // Every ghost walks from its start into a cycle that is a whole number
+    // of passes over the directions long, and ends in its 'Z' node. Distinct
+    // prime numbers of passes keep the cycles from lining up early.
+    let mut passes = [2, 3, 5, 7, 11, 13];
+    rng.shuffle(&mut passes);
+
+    let mut ghosts = Vec::new();
+
+    for (ghost, passes) in passes.iter().take((size / 10).clamp(1, 6)).enumerate() {
+        let cycle = len * passes;
+        let mut nodes = vec![match ghost {
+            0 => String::from("AAA"),
+            _ => name(rng, b"A"),
+        }];
+
+        for _ in 1..cycle {
+            nodes.push(name(rng, &LETTERS[1..25]));
+        }
+
+        nodes.push(match ghost {
+            0 => String::from("ZZZ"),
+            _ => name(rng, b"Z"),
+        });
+
+        ghosts.push(nodes);
+    }
+
+    let all = ghosts.concat();
+    let mut lines = Vec::new();
+
+    for nodes in &ghosts {
+        for (step, node) in nodes.iter().enumerate() {
+            let next = &nodes[step % (nodes.len() - 1) + 1];
+            let decoy = rng.pick(&all);
+
+            let (left, right) = match directions.as_bytes()[step % len] {
+                b'L' => (next, decoy),
+                _ => (decoy, next),
+            };
+
+            lines.push(format!("{node} = ({left}, {right})"));
+        }
+    }
+
+    rng.shuffle(&mut lines);
+
+    format!("{directions}\n\n{}\n", lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::parse::ParseError;
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day08>();
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day08>();
+    }
+
+    #[test]
+    fn generated_inputs() {
+        common::generate::check::<Day08>();
+    }
+
+    #[test]
+    fn parse_errors() {
+        let res = Day08::parse("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA ZZZ)\n").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (4, 12));
+        assert_eq!(res.expected, "','");
+
+        let res = Day08::parse("LXR\n\nAAA = (BBB, BBB)\n").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (1, 2));
+        assert_eq!(res.expected, "'L', 'R' or a line break");
+    }
+}
@@ -0,0 +1,200 @@
+//! Day 9: Mirage Maintenance. Sequences of readings, extrapolated one step
+//! forwards and backwards through their differences.
+
+use anyhow::Context;
+use common::{
+    example,
+    example::Example,
+    generate::Rng,
+    parse::{parse_lines, parse_lines_lenient, IResult, ParseError},
+    Solution,
+};
+use nom::{
+    character::complete::{i64, space0},
+    combinator::map,
+    error::context,
+    multi::many1,
+    sequence::tuple,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day09.txt");
+    const EXAMPLES: &'static [Example] = &[example!("1.txt", Some("114"), Some("2"))];
+
+    type Parsed = Vec<Line>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_lines(input, map(line, Line))?)
+    }
+
+    fn parse_lenient(input: &str) -> anyhow::Result<(Self::Parsed, Vec<ParseError>)> {
+        Ok(parse_lines_lenient(input, map(line, Line)))
+    }
+
+    fn part1(lines: &Self::Parsed) -> anyhow::Result<i64> {
+        part01(lines)
+    }
+
+    fn part2(lines: &Self::Parsed) -> anyhow::Result<i64> {
+        part02(lines)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+/// A sequence of readings, never empty.
+#[derive(Debug)]
+pub struct Line(Vec<i64>);
+
+impl Line {
+    pub fn values(&self) -> &[i64] {
+        &self.0
+    }
+
+    /// The value that would come after the last reading.
+    pub fn next_value(&self) -> anyhow::Result<i64> {
+        let mut values = self.0.clone();
+        let mut acc = *values.last().context("empty sequence")?;
+
+        // A single value left over is a constant sequence, so running out of
+        // values ends the extrapolation just like reaching all zeroes.
+        while !values.iter().all(|v| *v == 0) {
+            values = values
+                .windows(2)
+                .filter_map(|data| match data {
+                    &[left, right] => Some(right.checked_sub(left)),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+                .context("differences overflow")?;
+
+            if let Some(last) = values.last() {
+                acc = acc
+                    .checked_add(*last)
+                    .context("extrapolated value overflows")?;
+            }
+        }
+
+        Ok(acc)
+    }
+
+    /// The value that would come before the first reading.
+    pub fn previous_value(&self) -> anyhow::Result<i64> {
+        let mut values = self.0.clone();
+        let mut acc = *values.first().context("empty sequence")?;
+
+        while !values.iter().all(|v| *v == 0) {
+            values = values
+                .windows(2)
+                .filter_map(|data| match data {
+                    &[left, right] => Some(left.checked_sub(right)),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+                .context("differences overflow")?;
+
+            if let Some(first) = values.first() {
+                acc = acc
+                    .checked_add(*first)
+                    .context("extrapolated value overflows")?;
+            }
+        }
+
+        Ok(acc)
+    }
+}
+
+fn part01(lines: &[Line]) -> anyhow::Result<i64> {
+    lines.iter().try_fold(0i64, |sum, line| {
+        sum.checked_add(line.next_value()?)
+            .context("sum of extrapolated values overflows")
+    })
+}
+
+fn part02(lines: &[Line]) -> anyhow::Result<i64> {
+    lines.iter().try_fold(0i64, |sum, line| {
+        sum.checked_add(line.previous_value()?)
+            .context("sum of extrapolated values overflows")
+    })
+}
+
+fn line(input: &str) -> IResult<&str, Vec<i64>> {
+    let number = context("a number", i64);
+    many1(map(tuple((number, space0)), |(num, _)| num))(input)
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    const VALUES: i64 = 21;
+
+    let mut lines = Vec::new();
+
+    for _ in 0..size.max(1) {
+        let coefficients = (0..=rng.range(0..=5))
+            .map(|_| rng.range(-10..=10))
+            .collect::<Vec<i64>>();
+        let start = rng.range(-10..=10);
+
+        let values = (start..start + VALUES)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| value * x + coefficient)
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+
+        lines.push(values.join(" "));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day09>();
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day09>();
+    }
+
+    #[test]
+    fn generated_inputs() {
+        common::generate::check::<Day09>();
+    }
+
+    #[test]
+    fn parse_errors() {
+        let res = Day09::parse("0 3 6 9\n1 3 x 10").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (2, 5));
+        assert_eq!(res.expected, "end of line");
+
+        let res = Day09::parse("0 3 6 9\n\n1 3 6 10").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (2, 1));
+        assert_eq!(res.expected, "a number");
+    }
+
+    #[test]
+    fn lenient_parse() {
+        let (lines, skipped) = Day09::parse_lenient("0 3 6 9\n1 3 x 10\n\n1 2 3").unwrap();
+        assert_eq!(Day09::part1(&lines).unwrap(), 16);
+        assert_eq!(
+            skipped.iter().map(|e| e.line).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+    }
+}
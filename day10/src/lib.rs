@@ -0,0 +1,401 @@
+//! Day 10: Pipe Maze. A grid of pipes hiding one loop that runs through the
+//! start tile.
+
+use anyhow::{bail, Context};
+use common::{example, example::Example, generate::Rng, parse::ParseError, Solution};
+use geo::{BoundingRect, Contains, Coord, LineString, Point, Polygon};
+use grid::{image::Rgb, Grid};
+use std::{fmt::Display, iter::repeat};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day10.txt");
+    const EXAMPLES: &'static [Example] = &[
+        example!("1.txt", Some("4"), None),
+        example!("2.txt", Some("8"), None),
+        example!("3.txt", None, Some("4")),
+        example!("4.txt", None, Some("4")),
+        example!("5.txt", None, Some("8")),
+        example!("6.txt", None, Some("10")),
+    ];
+
+    type Parsed = Grid<Tile>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parser(input)?)
+    }
+
+    fn part1(grid: &Self::Parsed) -> anyhow::Result<i64> {
+        part01(grid)
+    }
+
+    fn part2(grid: &Self::Parsed) -> anyhow::Result<i64> {
+        part02(grid)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+fn part01(grid: &Grid<Tile>) -> anyhow::Result<i64> {
+    Ok(build_path(grid)?.len() as i64 / 2)
+}
+
+fn part02(grid: &Grid<Tile>) -> anyhow::Result<i64> {
+    let path = build_path(grid)?;
+    let polygon = enclosure(grid, &path);
+    let bounds = polygon.bounding_rect().context("the loop has no corners")?;
+
+    let x_range = bounds.min().x as i64 + 1..bounds.max().x as i64;
+    let y_range = bounds.min().y as i64 + 1..bounds.max().y as i64;
+
+    Ok(x_range
+        .into_iter()
+        .flat_map(|x| repeat(x).zip(y_range.clone()))
+        .map(|(x, y)| Point::new(x as f64, y as f64))
+        .filter(|p| polygon.contains(p))
+        .count() as i64)
+}
+
+/// The loop as a polygon through its corners.
+fn enclosure(grid: &Grid<Tile>, path: &[(usize, usize)]) -> Polygon {
+    let verticies = path
+        .iter()
+        .filter(|&&(x, y)| grid[(x, y)].is_vertex())
+        .map(|&(x, y)| Coord {
+            x: x as f64,
+            y: y as f64,
+        })
+        .collect::<Vec<_>>();
+
+    Polygon::new(LineString::new(verticies), Vec::new())
+}
+
+/// The maze as an image: the loop in white from a red start, the tiles it
+/// encloses in green and the tiles outside it in blue.
+pub fn render(grid: &Grid<Tile>) -> anyhow::Result<Grid<Rgb>> {
+    let path = build_path(grid)?;
+    let polygon = enclosure(grid, &path);
+
+    let mut image = Grid::new(grid.width(), grid.height(), Rgb::BLUE);
+
+    for ((x, y), _) in grid.iter() {
+        if polygon.contains(&Point::new(x as f64, y as f64)) {
+            image[(x, y)] = Rgb::GREEN;
+        }
+    }
+
+    for &position in &path {
+        image[position] = Rgb::WHITE;
+    }
+
+    image[path[0]] = Rgb::RED;
+    Ok(image)
+}
+
+/// Walks the loop from the start, returning the position of every tile on it
+/// in order, beginning with the start.
+pub fn build_path(grid: &Grid<Tile>) -> anyhow::Result<Vec<(usize, usize)>> {
+    let start = grid
+        .iter()
+        .find(|(_, tile)| **tile == Tile::Start)
+        .map(|(position, _)| position);
+
+    let (mut position, mut direction) = start
+        .into_iter()
+        .flat_map(|start| Direction::ALL.map(|direction| (start, direction)))
+        .filter_map(|(start, direction)| {
+            let next = direction.step(grid, start)?;
+            grid[next].exit(direction)?;
+            Some((next, direction))
+        })
+        .next()
+        .context("no pipe connects to the start")?;
+
+    let mut path = vec![start.unwrap_or_default()];
+
+    loop {
+        let tile = grid[position];
+
+        if tile == Tile::Start {
+            break;
+        }
+
+        // A closed loop visits every tile at most once.
+        if path.len() > grid.width() * grid.height() {
+            bail!("the loop never returns to the start");
+        }
+
+        if tile == Tile::Ground {
+            bail!("the loop leads off the pipes");
+        }
+
+        path.push(position);
+
+        direction = tile
+            .exit(direction)
+            .with_context(|| format!("the loop is broken at a '{tile}' tile"))?;
+        position = direction
+            .step(grid, position)
+            .context("the loop leads off the pipes")?;
+    }
+
+    Ok(path)
+}
+
+fn parser(data: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(
+        data,
+        "a tile ('.', 'S', '-', '|', '7', 'J', 'L' or 'F')",
+        |c| {
+            let tile = match c {
+                '.' => Tile::Ground,
+                'S' => Tile::Start,
+                '-' => Tile::Horizontal,
+                '|' => Tile::Vertical,
+                '7' => Tile::SouthWest,
+                'J' => Tile::NorthWest,
+                'L' => Tile::NorthEast,
+                'F' => Tile::SouthEast,
+                _ => return None,
+            };
+
+            Some(tile)
+        },
+    )
+}
+
+/// A tile of the maze, with the pipes named for the directions they connect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    Ground,
+    Start,
+    Horizontal,
+    Vertical,
+    SouthWest,
+    NorthWest,
+    NorthEast,
+    SouthEast,
+}
+
+impl Tile {
+    /// The direction a pipe is left in when entered moving in `direction`.
+    pub fn exit(&self, direction: Direction) -> Option<Direction> {
+        use Direction::*;
+
+        let exit = match (self, direction) {
+            (Self::Horizontal, East | West) | (Self::Vertical, North | South) => direction,
+            (Self::SouthWest, East) | (Self::SouthEast, West) => South,
+            (Self::SouthWest, North) | (Self::NorthWest, South) => West,
+            (Self::NorthWest, East) | (Self::NorthEast, West) => North,
+            (Self::NorthEast, South) | (Self::SouthEast, North) => East,
+            _ => return None,
+        };
+
+        Some(exit)
+    }
+
+    /// Whether the loop can turn on this tile.
+    fn is_vertex(&self) -> bool {
+        !matches!(self, Self::Ground | Self::Horizontal | Self::Vertical)
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Self::Ground => '.',
+            Self::Start => 'S',
+            Self::Horizontal => '-',
+            Self::Vertical => '|',
+            Self::SouthWest => '7',
+            Self::NorthWest => 'J',
+            Self::NorthEast => 'L',
+            Self::SouthEast => 'F',
+        };
+
+        write!(f, "{c}")
+    }
+}
+
+/// A direction of travel, with north up the grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    const ALL: [Self; 4] = [Self::East, Self::West, Self::South, Self::North];
+
+    /// The position one tile away from `(x, y)`, if it is inside `grid`.
+    pub fn step(&self, grid: &Grid<Tile>, (x, y): (usize, usize)) -> Option<(usize, usize)> {
+        let (x, y) = match self {
+            Self::North => (Some(x), y.checked_sub(1)),
+            Self::South => (Some(x), y.checked_add(1)),
+            Self::East => (x.checked_add(1), Some(y)),
+            Self::West => (x.checked_sub(1), Some(y)),
+        };
+
+        let (x, y) = (x?, y?);
+        grid.get(x, y).map(|_| (x, y))
+    }
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    const RING: [(i64, i64); 8] = [
+        (0, -1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+        (0, 1),
+        (-1, 1),
+        (-1, 0),
+        (-1, -1),
+    ];
+
+    // The loop runs around the outline of a random blob of cells, whose
+    // corners are the tiles. The blob grows one cell at a time, and only by
+    // cells that keep it free of holes and of cells touching at a corner
+    // alone, so that its outline stays a single loop.
+    let side = size.max(3);
+    let cells = side - 1;
+    let mut blob = Grid::new(cells, cells, false);
+
+    let filled = |blob: &Grid<bool>, x: i64, y: i64| {
+        x >= 0 && y >= 0 && blob.get(x as usize, y as usize) == Some(&true)
+    };
+
+    let can_fill = |blob: &Grid<bool>, x: usize, y: usize| {
+        let ring = RING.map(|(dx, dy)| filled(blob, x as i64 + dx, y as i64 + dy));
+        let pinched = (1..8)
+            .step_by(2)
+            .any(|corner| ring[corner] && !ring[corner - 1] && !ring[(corner + 1) % 8]);
+        let runs = (0..8).filter(|&i| ring[i] && !ring[(i + 7) % 8]).count();
+
+        !pinched && runs == 1
+    };
+
+    let mut candidates = vec![(rng.range(0..=cells - 1), rng.range(0..=cells - 1))];
+    let target = rng.range(1..=(cells * cells / 2).max(1));
+    let mut count = 0;
+
+    while count < target && !candidates.is_empty() {
+        let index = rng.range(0..=candidates.len() - 1);
+        let (x, y) = candidates.swap_remove(index);
+
+        if blob[(x, y)] || (count > 0 && !can_fill(&blob, x, y)) {
+            continue;
+        }
+
+        blob[(x, y)] = true;
+        count += 1;
+        candidates.extend(blob.neighbours4(x, y));
+    }
+
+    let outline =
+        |(x1, y1): (i64, i64), (x2, y2): (i64, i64)| filled(&blob, x1, y1) != filled(&blob, x2, y2);
+
+    let mut tiles = Grid::new(side, side, '.');
+    let mut path = Vec::new();
+
+    for y in 0..side {
+        for x in 0..side {
+            let (cx, cy) = (x as i64, y as i64);
+            let north = outline((cx - 1, cy - 1), (cx, cy - 1));
+            let south = outline((cx - 1, cy), (cx, cy));
+            let west = outline((cx - 1, cy - 1), (cx - 1, cy));
+            let east = outline((cx, cy - 1), (cx, cy));
+
+            let tile = match (north, south, east, west) {
+                (true, true, false, false) => '|',
+                (false, false, true, true) => '-',
+                (true, false, true, false) => 'L',
+                (true, false, false, true) => 'J',
+                (false, true, false, true) => '7',
+                (false, true, true, false) => 'F',
+                _ => continue,
+            };
+
+            tiles[(x, y)] = tile;
+            path.push((x, y));
+        }
+    }
+
+    let (x, y) = *rng.pick(&path);
+    tiles[(x, y)] = 'S';
+
+    // Scatter junk pipes off the loop, but none that could be mistaken for
+    // a way out of the start.
+    let near_start = tiles.neighbours4(x, y).collect::<Vec<_>>();
+
+    for y in 0..side {
+        for x in 0..side {
+            if tiles[(x, y)] == '.' && !near_start.contains(&(x, y)) && rng.chance(0.5) {
+                tiles[(x, y)] = *rng.pick(b"-|7JLF") as char;
+            }
+        }
+    }
+
+    tiles
+        .rows()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day10>();
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day10>();
+    }
+
+    #[test]
+    fn generated_inputs() {
+        common::generate::check::<Day10>();
+    }
+
+    #[test]
+    fn render() {
+        let grid = Day10::parse(Day10::EXAMPLES[4].input).unwrap();
+        let image = super::render(&grid).unwrap();
+        let count = |colour| image.iter().filter(|(_, c)| **c == colour).count();
+
+        assert_eq!(image[(12, 4)], Rgb::RED);
+        assert_eq!(count(Rgb::GREEN), 8);
+        assert_eq!(
+            count(Rgb::WHITE) + 1,
+            2 * Day10::part1(&grid).unwrap() as usize
+        );
+        assert_eq!(image[(0, 0)], Rgb::BLUE);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let res = Day10::parse(".....\n.S-7.\n.|.x.").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (3, 4));
+        assert_eq!(
+            res.expected,
+            "a tile ('.', 'S', '-', '|', '7', 'J', 'L' or 'F')"
+        );
+
+        let res = Day10::parse(".....\n.S-7..\n.|.|.").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (2, 6));
+        assert_eq!(res.expected, "a row of 5 cells");
+    }
+}
@@ -0,0 +1,280 @@
+//! Day 11: Cosmic Expansion. An image of galaxies, where every empty row and
+//! column has grown since it was taken.
+
+use common::{example, example::Example, generate::Rng, parse::ParseError, Solution};
+use grid::{image::Rgb, Grid};
+use std::{
+    iter::{repeat, repeat_n},
+    ops::Range,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/day11.txt");
+    const EXAMPLES: &'static [Example] = &[example!("1.txt", Some("374"), Some("82000210"))];
+
+    type Parsed = Image;
+    type Answer = i64;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_data(input)?)
+    }
+
+    fn part1(image: &Self::Parsed) -> anyhow::Result<i64> {
+        Ok(part01(&image.galaxies))
+    }
+
+    fn part2(image: &Self::Parsed) -> anyhow::Result<i64> {
+        Ok(part02(&image.galaxies))
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        generate(size, rng)
+    }
+}
+
+fn part01(galaxies: &[Galaxy]) -> i64 {
+    distances(galaxies, 2)
+}
+
+fn part02(galaxies: &[Galaxy]) -> i64 {
+    distances(galaxies, 1_000_000)
+}
+
+/// The sum of the shortest distances between every pair of galaxies, with
+/// each empty row and column grown to `expansion` of them.
+pub fn distances(galaxies: &[Galaxy], expansion: i64) -> i64 {
+    let len: usize = galaxies.len();
+
+    (0..len)
+        .flat_map(|index| {
+            let first = repeat(index);
+            let second = Range {
+                start: index + 1,
+                end: len,
+            };
+
+            first.zip(second)
+        })
+        .map(|(left, right)| galaxies[left].distance(expansion - 1, &galaxies[right]))
+        .sum()
+}
+
+/// The image of the galaxies in white, with each empty row and column in grey
+/// and grown to `expansion` of them.
+pub fn render(image: &Image, expansion: usize) -> Grid<Rgb> {
+    let mut occupied = Grid::new(image.width, image.height, false);
+    for galaxy in &image.galaxies {
+        occupied[(galaxy.x as usize, galaxy.y as usize)] = true;
+    }
+
+    // For each row or column of the image, the original it was grown from,
+    // and whether that one was empty.
+    let grow = |lines: Vec<bool>| {
+        lines
+            .into_iter()
+            .enumerate()
+            .flat_map(|(index, empty)| {
+                let copies = if empty { expansion } else { 1 };
+                repeat_n((index, empty), copies)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let rows = grow(occupied.rows().map(|row| !row.contains(&true)).collect());
+    let columns = grow(
+        occupied
+            .columns()
+            .map(|mut column| !column.any(|galaxy| *galaxy))
+            .collect(),
+    );
+
+    let mut pixels = Grid::new(columns.len(), rows.len(), Rgb::BLACK);
+
+    for (y, &(row, empty_row)) in rows.iter().enumerate() {
+        for (x, &(column, empty_column)) in columns.iter().enumerate() {
+            pixels[(x, y)] = if occupied[(column, row)] {
+                Rgb::WHITE
+            } else if empty_row || empty_column {
+                Rgb::GREY
+            } else {
+                Rgb::BLACK
+            };
+        }
+    }
+
+    pixels
+}
+
+fn parse_data(data: &str) -> Result<Image, ParseError> {
+    let grid = Grid::parse(data, "'.' or '#'", |c| match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })?;
+
+    // The number of empty rows or columns before each index.
+    let expansions = |lines: Vec<bool>| {
+        lines
+            .into_iter()
+            .scan(0, |expansion, empty| {
+                let before = *expansion;
+                *expansion += i64::from(empty);
+                Some(before)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let y_expansions = expansions(grid.rows().map(|row| !row.contains(&true)).collect());
+    let x_expansions = expansions(
+        grid.columns()
+            .map(|mut column| !column.any(|galaxy| *galaxy))
+            .collect(),
+    );
+
+    let galaxies = grid
+        .iter()
+        .filter(|(_, galaxy)| **galaxy)
+        .map(|((x, y), _)| Galaxy {
+            x: x as i64,
+            y: y as i64,
+            x_expansion: x_expansions[x],
+            y_expansion: y_expansions[y],
+        })
+        .collect();
+
+    Ok(Image {
+        galaxies,
+        width: grid.width(),
+        height: grid.height(),
+    })
+}
+
+/// The galaxies, and the size of the image they were found in, which can
+/// end in empty rows and columns.
+#[derive(Debug)]
+pub struct Image {
+    pub galaxies: Vec<Galaxy>,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// A galaxy, at its position in the image and with the number of empty
+/// columns to its left and rows above it.
+#[derive(Debug)]
+pub struct Galaxy {
+    x: i64,
+    y: i64,
+    x_expansion: i64,
+    y_expansion: i64,
+}
+
+impl Galaxy {
+    /// The position of the galaxy in the image, before any expansion.
+    pub fn position(&self) -> (i64, i64) {
+        (self.x, self.y)
+    }
+
+    /// The Manhattan distance to `other`, with `expansion` extra rows or
+    /// columns added for each empty one in the image.
+    pub fn distance(&self, expansion: i64, other: &Galaxy) -> i64 {
+        let other_x = other.x + (other.x_expansion * expansion);
+        let other_y = other.y + (other.y_expansion * expansion);
+
+        let x = self.x + (self.x_expansion * expansion);
+        let y = self.y + (self.y_expansion * expansion);
+
+        (other_x - x).abs() + (other_y - y).abs()
+    }
+}
+
+fn generate(size: usize, rng: &mut Rng) -> String {
+    let side = size.max(1);
+
+    // Real images have a few rows and columns without galaxies to expand.
+    let empty = |rng: &mut Rng| (0..side).map(|_| rng.chance(0.15)).collect::<Vec<_>>();
+    let (empty_rows, empty_columns) = (empty(rng), empty(rng));
+
+    let mut lines = Vec::new();
+
+    for empty_row in &empty_rows {
+        let line = empty_columns
+            .iter()
+            .map(|empty_column| {
+                let galaxy = !empty_row && !empty_column && rng.chance(0.05);
+                if galaxy {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>();
+
+        lines.push(line);
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        common::example::check::<Day11>();
+    }
+
+    #[test]
+    fn fuzz_regressions() {
+        common::fuzz::regressions::<Day11>();
+    }
+
+    #[test]
+    fn generated_inputs() {
+        common::generate::check::<Day11>();
+    }
+
+    #[test]
+    fn render() {
+        let image = Day11::parse(Day11::EXAMPLES[0].input).unwrap();
+        let count = |image: &Grid<Rgb>, colour| image.iter().filter(|(_, c)| **c == colour).count();
+
+        let before = super::render(&image, 1);
+        assert_eq!((before.width(), before.height()), (10, 10));
+        assert_eq!(before[(3, 0)], Rgb::WHITE);
+        assert_eq!(before[(2, 0)], Rgb::GREY);
+        assert_eq!(count(&before, Rgb::WHITE), 9);
+
+        // Three empty columns and two empty rows, each doubled.
+        let after = super::render(&image, 2);
+        assert_eq!((after.width(), after.height()), (13, 12));
+        assert_eq!(after[(4, 0)], Rgb::WHITE);
+        assert_eq!(count(&after, Rgb::WHITE), 9);
+    }
+
+    #[test]
+    fn renders_trailing_space() {
+        let image = Day11::parse("#..\n...\n.#.\n...\n").unwrap();
+
+        let before = super::render(&image, 1);
+        assert_eq!((before.width(), before.height()), (3, 4));
+        assert_eq!(before[(2, 3)], Rgb::GREY);
+
+        // The last column and the last two rows are empty and grow too.
+        let after = super::render(&image, 3);
+        assert_eq!((after.width(), after.height()), (5, 8));
+        assert_eq!(after[(1, 4)], Rgb::WHITE);
+        assert_eq!(after[(4, 7)], Rgb::GREY);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let res = Day11::parse("...#......\n.......#..\n#...x.....").unwrap_err();
+        let res = res.downcast::<ParseError>().unwrap();
+        assert_eq!((res.line, res.column), (3, 5));
+        assert_eq!(res.expected, "'.' or '#'");
+    }
+}
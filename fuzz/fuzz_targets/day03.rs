@@ -0,0 +1,6 @@
+#![no_main]
+
+use common::cli::Part;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| common::fuzz::run::<day03::Day03>(data, Part::Both));
@@ -0,0 +1,8 @@
+#![no_main]
+
+use common::cli::Part;
+use libfuzzer_sys::fuzz_target;
+
+// Part 2 brute-forces every seed in the seed ranges, so arbitrary ranges
+// only ever time out.
+fuzz_target!(|data: &[u8]| common::fuzz::run::<day05::Day05>(data, Part::One));
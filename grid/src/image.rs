@@ -0,0 +1,110 @@
+//! Rendering grids as images, one square of a colour per cell, in formats
+//! that need no external tools: binary PPM and SVG.
+
+use crate::Grid;
+use std::fmt::Write;
+
+/// A colour, as red, green and blue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0x10, 0x10, 0x18);
+    pub const GREY: Self = Self(0x60, 0x60, 0x68);
+    pub const WHITE: Self = Self(0xf0, 0xf0, 0xf0);
+    pub const RED: Self = Self(0xe0, 0x40, 0x40);
+    pub const GREEN: Self = Self(0x40, 0xc0, 0x60);
+    pub const BLUE: Self = Self(0x40, 0x70, 0xd0);
+    pub const YELLOW: Self = Self(0xf0, 0xd0, 0x40);
+}
+
+/// The image as a binary PPM (P6), with each cell `scale` pixels square.
+pub fn ppm(image: &Grid<Rgb>, scale: usize) -> Vec<u8> {
+    let scale = scale.max(1);
+    let (width, height) = (image.width() * scale, image.height() * scale);
+
+    let mut data = format!("P6\n{width} {height}\n255\n").into_bytes();
+    data.reserve(width * height * 3);
+
+    for row in image.rows().take(image.height()) {
+        let line = row
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b].repeat(scale))
+            .collect::<Vec<_>>();
+
+        for _ in 0..scale {
+            data.extend(&line);
+        }
+    }
+
+    data
+}
+
+/// The image as an SVG, with each cell `scale` units square. Runs of cells of
+/// the same colour in a row are drawn as one rectangle.
+pub fn svg(image: &Grid<Rgb>, scale: usize) -> String {
+    let scale = scale.max(1);
+    let (width, height) = (image.width(), image.height());
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+         viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n",
+        width * scale,
+        height * scale
+    );
+
+    for (y, row) in image.rows().take(height).enumerate() {
+        let mut x = 0;
+
+        for run in row.chunk_by(|a, b| a == b) {
+            let Rgb(r, g, b) = run[0];
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{}\" height=\"1\" \
+                 fill=\"#{r:02x}{g:02x}{b:02x}\"/>",
+                run.len()
+            );
+            x += run.len();
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> Grid<Rgb> {
+        let mut image = Grid::new(3, 2, Rgb::BLACK);
+        image[(2, 0)] = Rgb(1, 2, 3);
+        image
+    }
+
+    #[test]
+    fn writes_ppm() {
+        let data = ppm(&image(), 2);
+        let header = b"P6\n6 4\n255\n";
+
+        assert_eq!(&data[..header.len()], header);
+        assert_eq!(data.len(), header.len() + 6 * 4 * 3);
+
+        // The last two pixels of the first two rows are the scaled up cell.
+        let pixels = &data[header.len()..];
+        assert_eq!(&pixels[12..18], &[1, 2, 3, 1, 2, 3]);
+        assert_eq!(&pixels[30..36], &[1, 2, 3, 1, 2, 3]);
+        assert_eq!(&pixels[48..51], &[0x10, 0x10, 0x18]);
+    }
+
+    #[test]
+    fn writes_svg() {
+        let svg = svg(&image(), 4);
+
+        assert!(svg.contains("width=\"12\" height=\"8\" viewBox=\"0 0 3 2\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#101018\"/>"));
+        assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"1\" height=\"1\" fill=\"#010203\"/>"));
+        assert!(svg.contains("<rect x=\"0\" y=\"1\" width=\"3\" height=\"1\" fill=\"#101018\"/>"));
+        assert_eq!(svg.matches("<rect").count(), 3);
+    }
+}
@@ -0,0 +1,299 @@
+use common::parse::ParseError;
+use std::ops::{Index, IndexMut};
+
+pub mod image;
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A dense, row-major 2D grid, addressed by `(x, y)` with the origin in the
+/// top left corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses one cell per character, with each line of `input` a row.
+    /// `cell` returns `None` for characters that are not a valid cell, which
+    /// are reported as not being `expected`. Every row must be as wide as the
+    /// first, and blank lines at the end of the input are ignored.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (y, line) in input.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let error = |column: usize, expected: String| ParseError {
+                line: y + 1,
+                column: column + 1,
+                text: line.to_string(),
+                expected,
+            };
+
+            let mut row_width = 0;
+
+            for (x, c) in line.chars().enumerate() {
+                if let Some(width) = width.filter(|&width| x >= width) {
+                    return Err(error(x, format!("a row of {width} cells")));
+                }
+
+                cells.push(cell(c).ok_or_else(|| error(x, expected.to_string()))?);
+                row_width += 1;
+            }
+
+            match width {
+                Some(width) if row_width < width => {
+                    return Err(error(row_width, format!("a row of {width} cells")));
+                }
+                Some(_) => {}
+                None => width = Some(row_width),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(|index| &mut self.cells[index])
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index % width, index / width), cell))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero size, and a grid without columns has no
+        // cells to chunk anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells[x..].iter().step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.cells[x..].iter().step_by(self.width))
+    }
+
+    /// Positions of the orthogonal neighbours of `(x, y)` inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Positions of the orthogonal and diagonal neighbours of `(x, y)`
+    /// inside the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    /// The grid flipped over its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).map(move |y| self[(x, y)].clone()))
+            .collect();
+
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(*dx)?;
+            let y = y.checked_add_signed(*dy)?;
+            self.index_of(x, y).map(|_| (x, y))
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        let index = self
+            .index_of(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside the grid"));
+        &self.cells[index]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let index = self
+            .index_of(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside the grid"));
+        &mut self.cells[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse() {
+        let grid = digits("123\n456\n\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 1), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(grid[(1, 0)], 2);
+
+        let grid = digits("").unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let res = digits("123\n4x6").unwrap_err();
+        assert_eq!((res.line, res.column), (2, 2));
+        assert_eq!(res.expected, "a digit");
+
+        let res = digits("123\n4567").unwrap_err();
+        assert_eq!((res.line, res.column), (2, 4));
+        assert_eq!(res.expected, "a row of 3 cells");
+
+        let res = digits("123\n45").unwrap_err();
+        assert_eq!((res.line, res.column), (2, 3));
+        assert_eq!(res.expected, "a row of 3 cells");
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = digits("123\n456").unwrap();
+
+        let rows = grid.rows().collect::<Vec<_>>();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+
+        let columns = grid
+            .columns()
+            .map(|column| column.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert!(grid.column(3).is_none());
+
+        let transposed = grid.transpose();
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed.row(2), Some(&[3, 6][..]));
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits("123\n456\n789").unwrap();
+        let values = |positions: Vec<(usize, usize)>| {
+            positions
+                .into_iter()
+                .map(|(x, y)| grid[(x, y)])
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(values(grid.neighbours4(1, 1).collect()), vec![2, 6, 8, 4]);
+        assert_eq!(values(grid.neighbours4(0, 0).collect()), vec![2, 4]);
+        assert_eq!(
+            values(grid.neighbours8(1, 1).collect()),
+            vec![1, 2, 3, 6, 9, 8, 7, 4]
+        );
+        assert_eq!(values(grid.neighbours8(2, 2).collect()), vec![5, 6, 8]);
+    }
+
+    #[test]
+    fn iter_and_mutation() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[(1, 0)] = 1;
+        *grid.get_mut(0, 1).unwrap() = 2;
+
+        let cells = grid.iter().collect::<Vec<_>>();
+        assert_eq!(
+            cells,
+            vec![((0, 0), &0), ((1, 0), &1), ((0, 1), &2), ((1, 1), &0)]
+        );
+        assert_eq!(grid.map(|c| c * 10)[(0, 1)], 20);
+    }
+}